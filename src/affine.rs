@@ -0,0 +1,60 @@
+use crate::*;
+
+#[cfg_attr(doc, katexit::katexit)]
+/// the components of an affine transform given by [`AffineDecomposition::decompose_affine`]
+///
+/// The transform is recomposed as $T R S H$, where $T$ is the translation, $R$ is the rotation,
+/// $S$ is the diagonal scale, and $H$ is the upper unitriangular shear
+/// $$
+/// H = \begin{pmatrix} 1 & h_x & h_y \\ 0 & 1 & h_z \\ 0 & 0 & 1 \end{pmatrix},
+/// $$
+/// where $(h_x, h_y, h_z)$ is `shear`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AffineComponents<F> {
+    /// the translation
+    pub translation: Vector3<F>,
+    /// the rotation
+    pub rotation: Quaternion<F>,
+    /// the scale along the axes, whose last component is negative if the transform is a reflection
+    pub scale: Vector3<F>,
+    /// the entries of the shear above the diagonal
+    pub shear: Vector3<F>,
+}
+
+impl<F: BaseFloat> AffineComponents<F> {
+    /// Returns the shear as a matrix.
+    pub fn shear_matrix(&self) -> Matrix3<F> {
+        let (zero, one) = (F::zero(), F::one());
+        let h = self.shear;
+        Matrix3::new(one, zero, zero, h.x, one, zero, h.y, h.z, one)
+    }
+    /// Returns the recomposed affine transform.
+    pub fn recompose(&self) -> Matrix4<F> {
+        let linear =
+            Matrix3::from(self.rotation) * Matrix3::from_diagonal(self.scale) * self.shear_matrix();
+        Matrix4::from_translation(self.translation) * Matrix4::from(linear)
+    }
+}
+
+impl<F: BaseFloat> AffineDecomposition for Matrix4<F> {
+    fn decompose_affine(self) -> Result<AffineComponents<F>, DecompositionError<F>> {
+        let (zero, one) = (F::zero(), F::one());
+        dense::check_finite(&self.into())?;
+        if self[0][3] != zero || self[1][3] != zero || self[2][3] != zero || self[3][3] != one {
+            return Err(DecompositionError::NotAffine);
+        }
+        let linear = Matrix3::from_cols(self[0].truncate(), self[1].truncate(), self[2].truncate());
+        let (mut k, a, n) = linear.iwasawa_decomposition()?;
+        let mut scale = Vector3::new(a[0][0], a[1][1], a[2][2]);
+        if k.determinant() < zero {
+            k[2] = -k[2];
+            scale.z = -scale.z;
+        }
+        Ok(AffineComponents {
+            translation: self[3].truncate(),
+            rotation: Quaternion::from(k).normalize(),
+            scale,
+            shear: Vector3::new(n[1][0], n[2][0], n[2][1]),
+        })
+    }
+}
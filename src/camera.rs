@@ -0,0 +1,76 @@
+use crate::*;
+
+/// the intrinsic matrix, the rotation and the center of a camera
+pub type CameraParameters<F> = (Matrix3<F>, Matrix3<F>, Point3<F>);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Returns $(K, R, C)$ of the camera projection $P = (M | t) = \lambda K (R | -RC)$.
+///
+/// - $K$: intrinsic matrix, upper triangular with the positive diagonal, normalized by $K_{22} = 1$
+/// - $R$: rotation of the camera, a proper rotation matrix
+/// - $C$: camera center, i.e. $P (C, 1)^T = 0$
+///
+/// $K$ and $R$ are given by [`QrDecomposition::rq`] of $M$, after the sign of $P$ is flipped
+/// if $\det M < 0$, and $C = -M^{-1} t$ is solved by [`LuDecomposition::solve`].
+///
+/// # Errors
+///
+/// Returns [`DecompositionError::RankDeficient`] if $M$ is singular, and
+/// [`DecompositionError::NonFinite`] if $P$ has an infinite or NaN entry.
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::*;
+/// const EPS: f64 = 1.0e-10;
+///
+/// let k = Matrix3::new(800.0, 0.0, 0.0, 0.5, 600.0, 0.0, 320.0, 240.0, 1.0);
+/// let r = Matrix3::from_axis_angle(Vector3::new(0.6, 0.0, 0.8), Rad(0.5));
+/// let c = Point3::new(1.0, -2.0, 3.0);
+/// // projection scaled by a negative factor
+/// let (m, t) = (k * r * -2.0, k * r * c.to_vec() * 2.0);
+/// let (k0, r0, c0) = camera::decompose_projection(m, t).unwrap();
+/// assert!((k0 - k).norm_l1() < EPS);
+/// assert!((r0 - r).norm_l1() < EPS);
+/// assert!((c0 - c).magnitude() < EPS);
+/// ```
+pub fn decompose_projection<F: BaseFloat>(
+    m: Matrix3<F>,
+    t: Vector3<F>,
+) -> Result<CameraParameters<F>, DecompositionError<F>> {
+    let center = -m.solve(t)?;
+    let m = match m.determinant() < F::zero() {
+        true => -m,
+        false => m,
+    };
+    let (k, r) = m.rq();
+    Ok((k / k[2][2], r, Point3::from_vec(center)))
+}
+
+/// Returns the decomposition [`decompose_projection`] of the camera projection given by the first
+/// three rows of `mat`. The last row of `mat` is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::*;
+/// const EPS: f64 = 1.0e-10;
+///
+/// let view = Matrix4::look_at_rh(
+///     Point3::new(1.0, 2.0, 3.0),
+///     Point3::new(0.0, 0.0, 0.0),
+///     Vector3::unit_z(),
+/// );
+/// let (k, r, c) = camera::decompose_projection_matrix4(view).unwrap();
+/// assert!((k - Matrix3::identity()).norm_l1() < EPS);
+/// assert!(f64::abs(r.determinant() - 1.0) < EPS);
+/// assert!((c - Point3::new(1.0, 2.0, 3.0)).magnitude() < EPS);
+/// ```
+pub fn decompose_projection_matrix4<F: BaseFloat>(
+    mat: Matrix4<F>,
+) -> Result<CameraParameters<F>, DecompositionError<F>> {
+    let m = Matrix3::from_cols(mat[0].truncate(), mat[1].truncate(), mat[2].truncate());
+    decompose_projection(m, mat[3].truncate())
+}
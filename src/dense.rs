@@ -0,0 +1,1089 @@
+//! Kernels on column-major arrays, shared by the implementations for `Matrix2`, `Matrix3` and `Matrix4`.
+//!
+//! As in cgmath, `a[j][i]` is the entry in the `i`-th row and the `j`-th column.
+
+#![allow(clippy::needless_range_loop)]
+
+use crate::*;
+
+/// square matrix stored in columns
+pub(crate) type Mat<F, const N: usize> = [[F; N]; N];
+
+/// a pair of an eigen value and an eigen vector
+pub(crate) type EigenPair<F, const N: usize> = (Complex<F>, Option<[Complex<F>; N]>);
+
+/// the orthonormal, the diagonal and the unipotent factors of the Iwasawa decomposition
+pub(crate) type Iwasawa<F, const N: usize> = (Mat<F, N>, [F; N], Mat<F, N>);
+
+/// Returns the eigen vectors paired with `eigens`, the eigen values of `a`, which are kept as given.
+///
+/// The eigen vector for `eigens[j]` is taken from the numerical null space of `a - eigens[j]`, whose rank is
+/// decided by the cube root of the machine epsilon relative to the magnitude of `a`, the accuracy of
+/// a repeated root of the characteristic polynomial. Only to build a basis of the eigen space of
+/// a repeated eigen value, the vector is orthogonalized against the vectors of the preceding eigen values
+/// within the same tolerance. If no vector independent of them up to the square root of the machine
+/// epsilon is found, the eigen space is smaller than the multiplicity, and the vector is `None`.
+/// Every vector is also `None` if `a` has an infinite or NaN entry, and so is the vector of a non-finite
+/// eigen value.
+pub(crate) fn eigenvectors<F: BaseFloat, const N: usize>(
+    a: [[F; N]; N],
+    eigens: [Complex<F>; N],
+) -> [EigenPair<F, N>; N] {
+    let zero = Complex::new(F::zero(), F::zero());
+    let scale = a
+        .iter()
+        .flatten()
+        .fold(F::zero(), |max, x| F::max(max, F::abs(*x)));
+    let tol = F::cbrt(F::epsilon()) * scale;
+    let tol_indep = F::sqrt(F::epsilon());
+
+    let mut res: [EigenPair<F, N>; N] = eigens.map(|e| (e, None));
+    if check_finite(&a).is_err() {
+        return res;
+    }
+    for j in (0..N).filter(|&j| eigens[j].re.is_finite() && eigens[j].im.is_finite()) {
+        let near: Vec<[Complex<F>; N]> = (0..j)
+            .filter(|&k| (eigens[k] - eigens[j]).norm() <= tol)
+            .filter_map(|k| res[k].1)
+            .collect();
+        let mut b = a.map(|col| col.map(|x| Complex::new(x, F::zero())));
+        (0..N).for_each(|k| b[k][k] -= eigens[j]);
+        let basis = complex_null_space(b, tol, near.len() + 1);
+        let (norm, w, orth) = basis
+            .iter()
+            .map(|w| {
+                let mut orth = *w;
+                near.iter().for_each(|v| {
+                    let r = (0..N).fold(zero, |sum, i| sum + v[i].conj() * w[i]);
+                    (0..N).for_each(|i| orth[i] -= v[i] * r);
+                });
+                let norm = F::sqrt(orth.iter().fold(F::zero(), |sum, x| sum + x.norm_sqr()));
+                (norm, *w, orth)
+            })
+            .max_by(|x, y| total_cmp(&x.0, &y.0))
+            .unwrap();
+        if norm > tol_indep {
+            res[j].1 = Some(match basis.len() > 1 {
+                true => normalize_phase(orth),
+                false => w,
+            });
+        }
+    }
+    res
+}
+
+/// Returns `x` normalized so that its component with the maximum modulus is real positive.
+fn normalize_phase<F: BaseFloat, const N: usize>(x: [Complex<F>; N]) -> [Complex<F>; N] {
+    let norm = F::sqrt(x.iter().fold(F::zero(), |sum, x| sum + x.norm_sqr()));
+    let max = x
+        .iter()
+        .copied()
+        .max_by(|x, y| total_cmp(&x.norm_sqr(), &y.norm_sqr()))
+        .unwrap();
+    let phase = max.conj() / max.norm();
+    x.map(|x| x * phase / norm)
+}
+
+/// Returns at most `dim` orthonormal vectors of the numerical null space of `b`.
+///
+/// The elimination stops at a zero pivot, at a pivot not greater than `tol` once the rank reaches `N - dim`,
+/// and always before the rank reaches `N`, so at least one vector is returned.
+fn complex_null_space<F: BaseFloat, const N: usize>(
+    mut b: [[Complex<F>; N]; N],
+    tol: F,
+    dim: usize,
+) -> Vec<[Complex<F>; N]> {
+    let zero = Complex::new(F::zero(), F::zero());
+    // Gaussian elimination with complete pivoting
+    let mut cols: [usize; N] = std::array::from_fn(|j| j);
+    let mut rank = 0;
+    while rank + 1 < N {
+        let (mut pi, mut pj, mut max) = (rank, rank, F::zero());
+        for j in rank..N {
+            for i in rank..N {
+                let norm = b[cols[j]][i].norm();
+                if norm > max {
+                    (pi, pj, max) = (i, j, norm);
+                }
+            }
+        }
+        if max == F::zero() || (max <= tol && rank + dim >= N) {
+            break;
+        }
+        b.iter_mut().for_each(|col| col.swap(rank, pi));
+        cols.swap(rank, pj);
+        let pivot = b[cols[rank]][rank];
+        for i in rank + 1..N {
+            let f = b[cols[rank]][i] / pivot;
+            for &c in &cols[rank..] {
+                b[c][i] -= f * b[c][rank];
+            }
+        }
+        rank += 1;
+    }
+
+    let mut res: Vec<[Complex<F>; N]> = Vec::new();
+    for free in rank..usize::min(rank + dim, N) {
+        let mut x = [zero; N];
+        x[cols[free]] = Complex::new(F::one(), F::zero());
+        for k in (0..rank).rev() {
+            let sum = cols[k + 1..]
+                .iter()
+                .fold(zero, |sum, &c| sum + b[c][k] * x[c]);
+            x[cols[k]] = -sum / b[cols[k]][k];
+        }
+        // Gram-Schmidt orthonormalization
+        res.iter().for_each(|v| {
+            let r = (0..N).fold(zero, |sum, i| sum + v[i].conj() * x[i]);
+            (0..N).for_each(|i| x[i] -= v[i] * r);
+        });
+        res.push(normalize_phase(x));
+    }
+    res
+}
+
+/// Compares floating point numbers in the total order where NaN is greater than any other number.
+pub(crate) fn total_cmp<F: BaseFloat>(x: &F, y: &F) -> std::cmp::Ordering {
+    x.partial_cmp(y)
+        .unwrap_or_else(|| x.is_nan().cmp(&y.is_nan()))
+}
+
+/// Returns the identity matrix.
+pub(crate) fn identity<F: BaseFloat, const N: usize>() -> [[F; N]; N] {
+    std::array::from_fn(|j| std::array::from_fn(|i| if i == j { F::one() } else { F::zero() }))
+}
+
+/// Fails with [`DecompositionError::NonFinite`] if `a` has an infinite or NaN entry.
+pub(crate) fn check_finite<F: BaseFloat, const N: usize>(
+    a: &Mat<F, N>,
+) -> Result<(), DecompositionError<F>> {
+    match a.iter().flatten().all(|x| x.is_finite()) {
+        true => Ok(()),
+        false => Err(DecompositionError::NonFinite),
+    }
+}
+
+/// Returns the eigen values in ascending order and the orthogonal matrix whose columns are
+/// the corresponding eigen vectors of the symmetric part of `a`, by the cyclic Jacobi method.
+/// If `a` has an infinite or NaN entry, all the entries of the results are NaN.
+pub(crate) fn symmetric_eigen<F: BaseFloat, const N: usize>(
+    a: [[F; N]; N],
+) -> ([F; N], [[F; N]; N]) {
+    if check_finite(&a).is_err() {
+        return ([F::nan(); N], [[F::nan(); N]; N]);
+    }
+    let two = F::one() + F::one();
+    let mut a: [[F; N]; N] =
+        std::array::from_fn(|j| std::array::from_fn(|i| (a[j][i] + a[i][j]) / two));
+    let mut v = identity::<F, N>();
+    let eps = F::epsilon();
+    for _ in 0..64 {
+        let (mut off, mut total) = (F::zero(), F::zero());
+        for j in 0..N {
+            for i in 0..N {
+                let sq = a[j][i] * a[j][i];
+                total += sq;
+                if i != j {
+                    off += sq;
+                }
+            }
+        }
+        if off <= eps * eps * total {
+            break;
+        }
+        for p in 0..N {
+            for q in p + 1..N {
+                let apq = a[q][p];
+                if apq == F::zero() {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (two * apq);
+                let t = match theta >= F::zero() {
+                    true => F::one() / (theta + F::hypot(theta, F::one())),
+                    false => -F::one() / (-theta + F::hypot(theta, F::one())),
+                };
+                let c = F::one() / F::hypot(t, F::one());
+                let s = t * c;
+                for k in 0..N {
+                    let (akp, akq) = (a[p][k], a[q][k]);
+                    a[p][k] = c * akp - s * akq;
+                    a[q][k] = s * akp + c * akq;
+                }
+                for k in 0..N {
+                    let (apk, aqk) = (a[k][p], a[k][q]);
+                    a[k][p] = c * apk - s * aqk;
+                    a[k][q] = s * apk + c * aqk;
+                }
+                a[q][p] = F::zero();
+                a[p][q] = F::zero();
+                for k in 0..N {
+                    let (vkp, vkq) = (v[p][k], v[q][k]);
+                    v[p][k] = c * vkp - s * vkq;
+                    v[q][k] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    let mut idx: [usize; N] = std::array::from_fn(|i| i);
+    idx.sort_by(|i, j| total_cmp(&a[*i][*i], &a[*j][*j]));
+    (idx.map(|i| a[i][i]), idx.map(|i| v[i]))
+}
+
+/// Returns the inner product of `x` and `y`.
+pub(crate) fn dot<F: BaseFloat, const N: usize>(x: [F; N], y: [F; N]) -> F {
+    (0..N).fold(F::zero(), |sum, i| sum + x[i] * y[i])
+}
+
+/// Returns `(k, a, n)` of the Iwasawa decomposition `m = k diag(a) n` by the modified Gram–Schmidt
+/// process with one reorthogonalization, so that `k` is orthogonal up to rounding errors even if `m` is
+/// ill-conditioned.
+///
+/// Fails if the norm of the component of a column orthogonal to the previous columns is not greater
+/// than `tol` times the norm of the column.
+pub(crate) fn iwasawa<F: BaseFloat, const N: usize>(
+    m: Mat<F, N>,
+    tol: F,
+) -> Result<Iwasawa<F, N>, DecompositionError<F>> {
+    check_finite(&m)?;
+    let mut k = [[F::zero(); N]; N];
+    let mut a = [F::zero(); N];
+    let mut n = identity::<F, N>();
+    for j in 0..N {
+        let mut v = m[j];
+        let mut r = [F::zero(); N];
+        for _ in 0..2 {
+            for i in 0..j {
+                let c = dot(v, k[i]);
+                (0..N).for_each(|l| v[l] -= c * k[i][l]);
+                r[i] += c;
+            }
+        }
+        let magnitude = F::sqrt(dot(v, v));
+        if magnitude <= tol * F::sqrt(dot(m[j], m[j])) {
+            return Err(DecompositionError::RankDeficient {
+                column: j,
+                magnitude,
+            });
+        }
+        (0..j).for_each(|i| n[j][i] = r[i] / a[i]);
+        a[j] = magnitude;
+        k[j] = v.map(|x| x / magnitude);
+    }
+    Ok((k, a, n))
+}
+
+/// Returns `(u, sigma, v)` such that `a = u diag(sigma) v^T` with orthogonal `u`, `v` and
+/// singular values `sigma` in descending order, by the one-sided Jacobi method.
+///
+/// `a` is scaled by the maximum absolute value of the entries so that the inner products do not overflow.
+/// If `a` has an infinite or NaN entry, all the entries of the results are NaN.
+pub(crate) fn svd<F: BaseFloat, const N: usize>(
+    a: [[F; N]; N],
+) -> ([[F; N]; N], [F; N], [[F; N]; N]) {
+    if check_finite(&a).is_err() {
+        return ([[F::nan(); N]; N], [F::nan(); N], [[F::nan(); N]; N]);
+    }
+    let two = F::one() + F::one();
+    let eps = F::epsilon();
+    let scale = match max_abs(&a) {
+        max if max > F::zero() => max,
+        _ => F::one(),
+    };
+    let mut u = a.map(|col| col.map(|x| x / scale));
+    let mut v = identity::<F, N>();
+    for _ in 0..64 {
+        let mut rotated = false;
+        for p in 0..N {
+            for q in p + 1..N {
+                let alpha = dot(u[p], u[p]);
+                let beta = dot(u[q], u[q]);
+                let gamma = dot(u[p], u[q]);
+                if F::abs(gamma) <= eps * F::sqrt(alpha) * F::sqrt(beta) {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = match zeta >= F::zero() {
+                    true => F::one() / (zeta + F::hypot(zeta, F::one())),
+                    false => -F::one() / (-zeta + F::hypot(zeta, F::one())),
+                };
+                let c = F::one() / F::hypot(t, F::one());
+                let s = t * c;
+                for k in 0..N {
+                    let (upk, uqk) = (u[p][k], u[q][k]);
+                    u[p][k] = c * upk - s * uqk;
+                    u[q][k] = s * upk + c * uqk;
+                    let (vpk, vqk) = (v[p][k], v[q][k]);
+                    v[p][k] = c * vpk - s * vqk;
+                    v[q][k] = s * vpk + c * vqk;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+    let norms = u.map(|col| F::sqrt(dot(col, col)));
+    let mut idx: [usize; N] = std::array::from_fn(|i| i);
+    idx.sort_by(|i, j| total_cmp(&norms[*j], &norms[*i]));
+    let norms = idx.map(|i| norms[i]);
+    let mut u = idx.map(|i| u[i]);
+    let v = idx.map(|i| v[i]);
+    (0..N).for_each(|j| match norms[j] > F::zero() {
+        true => u[j] = u[j].map(|x| x / norms[j]),
+        false => u[j] = complement(&u[..j]),
+    });
+    let sigma = norms.map(|x| x * scale);
+    (u, sigma, v)
+}
+
+/// Returns a unit vector orthogonal to all of the orthonormal vectors `basis`.
+pub(crate) fn complement<F: BaseFloat, const N: usize>(basis: &[[F; N]]) -> [F; N] {
+    (0..N)
+        .map(|k| {
+            let mut x = [F::zero(); N];
+            x[k] = F::one();
+            // projection twice for the orthogonality
+            for _ in 0..2 {
+                basis.iter().for_each(|b| {
+                    let r = dot(*b, x);
+                    (0..N).for_each(|i| x[i] -= r * b[i]);
+                });
+            }
+            x
+        })
+        .max_by(|x, y| dot(*x, *x).partial_cmp(&dot(*y, *y)).unwrap())
+        .map(|x| {
+            let norm = F::sqrt(dot(x, x));
+            x.map(|x| x / norm)
+        })
+        .unwrap()
+}
+
+/// Returns the permutation matrix `p` such that the `j`-th column of `a p` is the `perm[j]`-th column of `a`.
+pub(crate) fn permutation_matrix<F: BaseFloat, const N: usize>(perm: [usize; N]) -> Mat<F, N> {
+    perm.map(|k| std::array::from_fn(|i| if i == k { F::one() } else { F::zero() }))
+}
+
+/// Returns `(q, r, perm, rank)` such that the `j`-th column of `q r` is the `perm[j]`-th column of `a`,
+/// by the Householder reflections. The diagonal of `r` is non-negative.
+///
+/// If `pivoting` is `true`, the column with the maximum norm is taken in each step, so that the diagonal
+/// of `r` is non-increasing, and `rank` is the number of the diagonal entries greater than `N ε r[0][0]`.
+/// Otherwise, `perm` is the identity and `rank` is `N`.
+///
+/// If `a` has an infinite or NaN entry, all the entries of `q` and `r` are NaN, `perm` is the identity,
+/// and `rank` is zero with pivoting.
+pub(crate) fn householder_qr<F: BaseFloat, const N: usize>(
+    mut a: Mat<F, N>,
+    pivoting: bool,
+) -> (Mat<F, N>, Mat<F, N>, [usize; N], usize) {
+    if check_finite(&a).is_err() {
+        let nan = [[F::nan(); N]; N];
+        let rank = if pivoting { 0 } else { N };
+        return (nan, nan, std::array::from_fn(|j| j), rank);
+    }
+    let two = F::one() + F::one();
+    let mut q = identity::<F, N>();
+    let mut perm: [usize; N] = std::array::from_fn(|j| j);
+    for k in 0..N {
+        if pivoting {
+            let norm = |j: usize| (k..N).fold(F::zero(), |sum, i| sum + a[j][i] * a[j][i]);
+            let p = (k..N)
+                .max_by(|i, j| total_cmp(&norm(*i), &norm(*j)))
+                .unwrap();
+            a.swap(k, p);
+            perm.swap(k, p);
+        }
+        if k + 1 == N {
+            break;
+        }
+        // reflection of x = a[k][k..] to beta e_k
+        let alpha = F::sqrt((k..N).fold(F::zero(), |sum, i| sum + a[k][i] * a[k][i]));
+        if alpha == F::zero() {
+            continue;
+        }
+        let beta = match a[k][k] >= F::zero() {
+            true => -alpha,
+            false => alpha,
+        };
+        let mut v = [F::zero(); N];
+        (k..N).for_each(|i| v[i] = a[k][i]);
+        v[k] -= beta;
+        let vv = dot(v, v);
+        for j in k + 1..N {
+            let s = two * dot(v, a[j]) / vv;
+            (k..N).for_each(|i| a[j][i] -= s * v[i]);
+        }
+        a[k][k] = beta;
+        (k + 1..N).for_each(|i| a[k][i] = F::zero());
+        for i in 0..N {
+            let s = two * (k..N).fold(F::zero(), |sum, l| sum + q[l][i] * v[l]) / vv;
+            (k..N).for_each(|l| q[l][i] -= s * v[l]);
+        }
+    }
+    for k in 0..N {
+        if a[k][k] < F::zero() {
+            (k..N).for_each(|j| a[j][k] = -a[j][k]);
+            q[k] = q[k].map(|x| -x);
+        }
+    }
+    let rank = match pivoting {
+        true => {
+            let tol = F::from(N).unwrap() * F::epsilon() * a[0][0];
+            (0..N).take_while(|&k| a[k][k] > tol).count()
+        }
+        false => N,
+    };
+    (q, a, perm, rank)
+}
+
+/// Returns `(lu, perm)` of the LU decomposition with partial pivoting: the `i`-th row of `L U` is
+/// the `perm[i]`-th row of `a`, where `L` is the unit lower triangular matrix stored below the diagonal
+/// of `lu` and `U` is the upper triangular part of `lu`.
+///
+/// Fails if a pivot is not greater than `N ε` times the maximum absolute value of the entries of `a`.
+pub(crate) fn lu<F: BaseFloat, const N: usize>(
+    mut a: Mat<F, N>,
+) -> Result<(Mat<F, N>, [usize; N]), DecompositionError<F>> {
+    check_finite(&a)?;
+    let max = a
+        .iter()
+        .flatten()
+        .fold(F::zero(), |max, x| F::max(max, F::abs(*x)));
+    let tol = F::from(N).unwrap() * F::epsilon() * max;
+    let mut perm: [usize; N] = std::array::from_fn(|i| i);
+    for k in 0..N {
+        let p = (k..N)
+            .max_by(|i, j| F::abs(a[k][*i]).partial_cmp(&F::abs(a[k][*j])).unwrap())
+            .unwrap();
+        let magnitude = F::abs(a[k][p]);
+        if magnitude <= tol {
+            return Err(DecompositionError::RankDeficient {
+                column: k,
+                magnitude,
+            });
+        }
+        a.iter_mut().for_each(|col| col.swap(k, p));
+        perm.swap(k, p);
+        for i in k + 1..N {
+            a[k][i] /= a[k][k];
+            for j in k + 1..N {
+                a[j][i] -= a[k][i] * a[j][k];
+            }
+        }
+    }
+    Ok((a, perm))
+}
+
+/// Returns the solution `x` of `a x = b` with `(lu, perm)`, the LU decomposition of `a`.
+pub(crate) fn lu_solve<F: BaseFloat, const N: usize>(
+    lu: &Mat<F, N>,
+    perm: &[usize; N],
+    b: [F; N],
+) -> [F; N] {
+    let mut x = perm.map(|i| b[i]);
+    for i in 0..N {
+        for j in 0..i {
+            x[i] -= lu[j][i] * x[j];
+        }
+    }
+    for i in (0..N).rev() {
+        for j in i + 1..N {
+            x[i] -= lu[j][i] * x[j];
+        }
+        x[i] /= lu[i][i];
+    }
+    x
+}
+
+/// Returns the unit lower triangular part and the upper triangular part of `lu`.
+pub(crate) fn split_lu<F: BaseFloat, const N: usize>(lu: Mat<F, N>) -> (Mat<F, N>, Mat<F, N>) {
+    let l = std::array::from_fn(|j| {
+        std::array::from_fn(|i| match i.cmp(&j) {
+            std::cmp::Ordering::Less => F::zero(),
+            std::cmp::Ordering::Equal => F::one(),
+            std::cmp::Ordering::Greater => lu[j][i],
+        })
+    });
+    let u =
+        std::array::from_fn(|j| std::array::from_fn(|i| if i <= j { lu[j][i] } else { F::zero() }));
+    (l, u)
+}
+
+/// Returns the lower triangular matrix `l` such that `a = l l^T`. Only the lower triangle of `a` is referred.
+pub(crate) fn cholesky<F: BaseFloat, const N: usize>(
+    a: Mat<F, N>,
+) -> Result<Mat<F, N>, DecompositionError<F>> {
+    check_finite(&a)?;
+    let mut l = [[F::zero(); N]; N];
+    for j in 0..N {
+        let value = (0..j).fold(a[j][j], |d, k| d - l[k][j] * l[k][j]);
+        if value <= F::zero() {
+            return Err(DecompositionError::NotPositiveDefinite { pivot: j, value });
+        }
+        l[j][j] = F::sqrt(value);
+        for i in j + 1..N {
+            let c = (0..j).fold(a[j][i], |c, k| c - l[k][i] * l[k][j]);
+            l[j][i] = c / l[j][j];
+        }
+    }
+    Ok(l)
+}
+
+/// Returns the unit lower triangular matrix `l` and the non-negative diagonal `d` such that
+/// `a = l diag(d) l^T`. Only the lower triangle of `a` is referred.
+///
+/// A pivot whose absolute value is not greater than `sqrt(ε)` times its own diagonal entry `a_jj`
+/// is regarded as zero if the rest of the column is also negligible, i.e. each entry `c_i` is not greater
+/// than `sqrt(ε)` times `sqrt(a_ii a_jj)`, the bound of the coupling of positive semi-definite matrices.
+/// The tolerances are relative to the rows and the columns, so that the rounding errors of singular positive
+/// semi-definite matrices are allowed without neglecting the couplings of the entries of small scales.
+pub(crate) fn ldlt<F: BaseFloat, const N: usize>(
+    a: Mat<F, N>,
+) -> Result<(Mat<F, N>, [F; N]), DecompositionError<F>> {
+    check_finite(&a)?;
+    let tol = F::sqrt(F::epsilon());
+    let mut l = identity::<F, N>();
+    let mut d = [F::zero(); N];
+    for j in 0..N {
+        let value = (0..j).fold(a[j][j], |v, k| v - l[k][j] * l[k][j] * d[k]);
+        let c: [F; N] = std::array::from_fn(|i| match i > j {
+            true => (0..j).fold(a[j][i], |c, k| c - l[k][i] * l[k][j] * d[k]),
+            false => F::zero(),
+        });
+        let negligible = F::abs(value) <= tol * F::abs(a[j][j])
+            && (j + 1..N).all(|i| F::abs(c[i]) <= tol * F::sqrt(F::abs(a[i][i] * a[j][j])));
+        if negligible {
+            d[j] = F::max(value, F::zero());
+        } else if value > F::zero() {
+            d[j] = value;
+            (j + 1..N).for_each(|i| l[j][i] = c[i] / value);
+        } else {
+            return Err(DecompositionError::NotPositiveDefinite { pivot: j, value });
+        }
+    }
+    Ok((l, d))
+}
+
+/// Returns the Householder vector `v` such that the reflection `I - 2 v v^T / (v^T v)` maps `x` to
+/// a multiple of `e_k`, ignoring the entries of `x` before `k`. Returns `None` if `x` is already so.
+fn householder_vector<F: BaseFloat, const N: usize>(x: [F; N], k: usize) -> Option<[F; N]> {
+    if (k + 1..N).all(|i| x[i] == F::zero()) {
+        return None;
+    }
+    let mut v: [F; N] = std::array::from_fn(|i| if i < k { F::zero() } else { x[i] });
+    let alpha = F::sqrt(dot(v, v));
+    match v[k] >= F::zero() {
+        true => v[k] += alpha,
+        false => v[k] -= alpha,
+    }
+    Some(v)
+}
+
+/// `a <- (I - 2 v v^T / (v^T v)) a`
+fn reflect_left<F: BaseFloat, const N: usize>(a: &mut Mat<F, N>, v: [F; N]) {
+    let two = F::one() + F::one();
+    let vv = dot(v, v);
+    for j in 0..N {
+        let s = two * dot(v, a[j]) / vv;
+        (0..N).for_each(|i| a[j][i] -= s * v[i]);
+    }
+}
+
+/// `a <- a (I - 2 v v^T / (v^T v))`
+fn reflect_right<F: BaseFloat, const N: usize>(a: &mut Mat<F, N>, v: [F; N]) {
+    let two = F::one() + F::one();
+    let vv = dot(v, v);
+    for i in 0..N {
+        let s = two * (0..N).fold(F::zero(), |sum, l| sum + a[l][i] * v[l]) / vv;
+        (0..N).for_each(|l| a[l][i] -= s * v[l]);
+    }
+}
+
+/// Returns `(q, h)` such that `a = q h q^T`, where `q` is orthogonal and `h` is upper Hessenberg,
+/// by the Householder reflections.
+pub(crate) fn hessenberg<F: BaseFloat, const N: usize>(mut a: Mat<F, N>) -> (Mat<F, N>, Mat<F, N>) {
+    let mut q = identity::<F, N>();
+    for k in 0..N.saturating_sub(2) {
+        if let Some(v) = householder_vector(a[k], k + 1) {
+            reflect_left(&mut a, v);
+            reflect_right(&mut a, v);
+            reflect_right(&mut q, v);
+            (k + 2..N).for_each(|i| a[k][i] = F::zero());
+        }
+    }
+    (q, a)
+}
+
+/// Returns `(q, t)` of the real Schur decomposition `a = q t q^T`, where `q` is orthogonal and `t` is
+/// quasi upper triangular, by the Hessenberg reduction and the Francis double shift QR steps.
+///
+/// The diagonal blocks of `t` are `1 x 1` for real eigen values and `2 x 2` for pairs of complex
+/// conjugate eigen values. Each `2 x 2` block is standardized as in LAPACK: its diagonal entries are
+/// equal and its off-diagonal entries have opposite signs.
+///
+/// Fails if `a` has an infinite or NaN entry, or if a block is not deflated in `30 N` steps.
+pub(crate) fn schur<F: BaseFloat, const N: usize>(
+    a: Mat<F, N>,
+) -> Result<(Mat<F, N>, Mat<F, N>), DecompositionError<F>> {
+    check_finite(&a)?;
+    let (mut q, mut t) = hessenberg(a);
+    let norm = t
+        .iter()
+        .flatten()
+        .fold(F::zero(), |max, x| F::max(max, F::abs(*x)));
+    let mut hi = N;
+    let mut iter = 0;
+    while hi > 0 {
+        // search the negligible sub-diagonal entry from the bottom
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let s = F::abs(t[lo - 1][lo - 1]) + F::abs(t[lo][lo]);
+            let s = if s == F::zero() { norm } else { s };
+            if F::abs(t[lo - 1][lo]) <= F::epsilon() * s {
+                t[lo - 1][lo] = F::zero();
+                break;
+            }
+            lo -= 1;
+        }
+        match hi - lo {
+            1 => hi -= 1,
+            2 => {
+                standardize_block(&mut q, &mut t, lo);
+                hi -= 2;
+            }
+            _ if iter < 30 * N => {
+                iter += 1;
+                francis_step(&mut q, &mut t, lo, hi, iter % 10 == 0);
+                continue;
+            }
+            _ => return Err(DecompositionError::NotConverged),
+        }
+        iter = 0;
+    }
+    Ok((q, t))
+}
+
+/// One implicit double shift QR step on the diagonal block of the rows and columns `lo..hi` of `t`.
+/// The shifts are the eigen values of the trailing `2 x 2` block, or the ad hoc ones if `exceptional`.
+fn francis_step<F: BaseFloat, const N: usize>(
+    q: &mut Mat<F, N>,
+    t: &mut Mat<F, N>,
+    lo: usize,
+    hi: usize,
+    exceptional: bool,
+) {
+    let (m, n) = (hi - 1, hi - 2);
+    // sum and product of the shifts
+    let (s, p) = match exceptional {
+        true => {
+            let w = F::abs(t[m - 1][m]) + F::abs(t[m - 2][m - 1]);
+            let h = t[m][m] + F::from(0.75).unwrap() * w;
+            (h + h, h * h + F::from(0.4375).unwrap() * w * w)
+        }
+        false => (t[n][n] + t[m][m], t[n][n] * t[m][m] - t[m][n] * t[n][m]),
+    };
+    // the first column of (t - s_1)(t - s_2)
+    let mut x = [F::zero(); N];
+    let (h00, h10, h01, h11, h21) = (
+        t[lo][lo],
+        t[lo][lo + 1],
+        t[lo + 1][lo],
+        t[lo + 1][lo + 1],
+        t[lo + 1][lo + 2],
+    );
+    x[lo] = h00 * h00 + h01 * h10 - s * h00 + p;
+    x[lo + 1] = h10 * (h00 + h11 - s);
+    x[lo + 2] = h10 * h21;
+    // chase the bulge
+    for k in lo..hi - 1 {
+        if let Some(v) = householder_vector(x, k) {
+            reflect_left(t, v);
+            reflect_right(t, v);
+            reflect_right(q, v);
+        }
+        if k > lo {
+            (k + 1..N).for_each(|i| t[k - 1][i] = F::zero());
+        }
+        x = std::array::from_fn(|i| match k < i && i < usize::min(k + 4, hi) {
+            true => t[k][i],
+            false => F::zero(),
+        });
+    }
+}
+
+/// Standardizes the `2 x 2` diagonal block of `t` at the `k`-th row and column by a rotation,
+/// which is accumulated in `q`. The block is made upper triangular if its eigen values are real,
+/// and otherwise its diagonal entries are made equal, following `dlanv2` of LAPACK.
+fn standardize_block<F: BaseFloat, const N: usize>(q: &mut Mat<F, N>, t: &mut Mat<F, N>, k: usize) {
+    let (a, b, c, d) = (t[k][k], t[k + 1][k], t[k][k + 1], t[k + 1][k + 1]);
+    let (cs, sn, real) = standard_rotation(a, b, c, d);
+    for j in 0..N {
+        let (x, y) = (t[j][k], t[j][k + 1]);
+        t[j][k] = cs * x + sn * y;
+        t[j][k + 1] = -sn * x + cs * y;
+    }
+    for m in [&mut *t, q] {
+        for i in 0..N {
+            let (x, y) = (m[k][i], m[k + 1][i]);
+            m[k][i] = cs * x + sn * y;
+            m[k + 1][i] = -sn * x + cs * y;
+        }
+    }
+    match real {
+        true => t[k][k + 1] = F::zero(),
+        false => {
+            let mean = (t[k][k] + t[k + 1][k + 1]) / (F::one() + F::one());
+            t[k][k] = mean;
+            t[k + 1][k + 1] = mean;
+        }
+    }
+}
+
+/// Returns `(cs, sn, real)` such that the rotation `R = [cs -sn; sn cs]` standardizes `R^T [a b; c d] R`,
+/// where `real` is whether the eigen values of the block are real.
+fn standard_rotation<F: BaseFloat>(a: F, b: F, c: F, d: F) -> (F, F, bool) {
+    let (zero, one) = (F::zero(), F::one());
+    let half = one / (one + one);
+    let sign = |x: F| if x >= zero { one } else { -one };
+    if c == zero {
+        return (one, zero, true);
+    } else if b == zero {
+        return (zero, one, true);
+    } else if a == d && sign(b) != sign(c) {
+        return (one, zero, false);
+    }
+    let p = (a - d) * half;
+    let bcmax = F::max(F::abs(b), F::abs(c));
+    let bcmis = F::min(F::abs(b), F::abs(c)) * sign(b) * sign(c);
+    let scale = F::max(F::abs(p), bcmax);
+    let z = p / scale * p + bcmax / scale * bcmis;
+    if z >= F::from(4.0).unwrap() * F::epsilon() {
+        // real eigen values: annihilate c
+        let z = p + sign(p) * F::sqrt(scale) * F::sqrt(z);
+        let tau = F::hypot(c, z);
+        return (z / tau, c / tau, true);
+    }
+    // complex or nearly equal real eigen values: make the diagonal entries equal
+    let sigma = b + c;
+    let tau = F::hypot(sigma, a - d);
+    let cs = F::sqrt(half * (one + F::abs(sigma) / tau));
+    let sn = -(p / (tau * cs)) * sign(sigma);
+    let (aa, bb) = (a * cs + b * sn, -a * sn + b * cs);
+    let (cc, dd) = (c * cs + d * sn, -c * sn + d * cs);
+    let (b, c) = (bb * cs + dd * sn, -aa * sn + cc * cs);
+    if c == zero {
+        (cs, sn, true)
+    } else if b == zero {
+        (-sn, cs, true)
+    } else if sign(b) == sign(c) {
+        // real eigen values: annihilate c
+        let (sab, sac) = (F::sqrt(F::abs(b)), F::sqrt(F::abs(c)));
+        let tau = one / F::sqrt(F::abs(b + c));
+        let (cs1, sn1) = (sab * tau, sac * tau);
+        (cs * cs1 - sn * sn1, cs * sn1 + sn * cs1, true)
+    } else {
+        (cs, sn, false)
+    }
+}
+
+/// Returns the eigen values of the quasi upper triangular matrix `t` given by [`schur`].
+pub(crate) fn schur_eigenvalues<F: BaseFloat, const N: usize>(t: &Mat<F, N>) -> [Complex<F>; N] {
+    let mut eigens = [Complex::new(F::zero(), F::zero()); N];
+    let mut k = 0;
+    while k < N {
+        if k + 1 < N && t[k][k + 1] != F::zero() {
+            let im = F::sqrt(F::abs(t[k + 1][k])) * F::sqrt(F::abs(t[k][k + 1]));
+            eigens[k] = Complex::new(t[k][k], im);
+            eigens[k + 1] = Complex::new(t[k + 1][k + 1], -im);
+            k += 2;
+        } else {
+            eigens[k] = Complex::new(t[k][k], F::zero());
+            k += 1;
+        }
+    }
+    eigens
+}
+
+/// Returns the principal square root of the quasi upper triangular matrix `t` given by [`schur`], by
+/// the recurrence of Björck and Hammarling in the real form of N. J. Higham, "Computing real square roots
+/// of a real matrix", 1987. The blocks are solved column by column from the diagonal ones, and the
+/// matrix is never inverted, so that the root is accurate even if `t` is ill-conditioned.
+///
+/// Fails if `t` has an eigen value on the closed negative real axis, where the real principal
+/// square root does not exist, or if `t` is not quasi upper triangular.
+pub(crate) fn schur_sqrt<F: BaseFloat, const N: usize>(t: &Mat<F, N>) -> Option<Mat<F, N>> {
+    let two = F::one() + F::one();
+    // the diagonal blocks as the pairs of the first index and the size
+    let mut blocks = Vec::new();
+    let mut k = 0;
+    while k < N {
+        let size = match k + 1 < N && t[k][k + 1] != F::zero() {
+            true => 2,
+            false => 1,
+        };
+        if k + size < N && t[k + size - 1][k + size] != F::zero() {
+            return None;
+        }
+        blocks.push((k, size));
+        k += size;
+    }
+    let mut u = [[F::zero(); N]; N];
+    for (jb, &(j, q)) in blocks.iter().enumerate() {
+        if q == 1 {
+            if t[j][j].is_nan() || t[j][j] <= F::zero() {
+                return None;
+            }
+            u[j][j] = F::sqrt(t[j][j]);
+        } else {
+            // The block is a I + X with the eigen values a ± i mu, where X^2 = -mu^2 I,
+            // and its root is alpha I + X / (2 alpha) with the root alpha + i beta of a + i mu.
+            let a = (t[j][j] + t[j + 1][j + 1]) / two;
+            let (x00, x11) = (t[j][j] - a, t[j + 1][j + 1] - a);
+            let mu2 = x00 * x11 - t[j + 1][j] * t[j][j + 1];
+            if mu2.is_nan() || mu2 <= F::zero() {
+                return None;
+            }
+            let mu = F::sqrt(mu2);
+            let modulus = F::hypot(a, mu);
+            let alpha = match a >= F::zero() {
+                true => F::sqrt((modulus + a) / two),
+                false => mu / (two * F::sqrt((modulus - a) / two)),
+            };
+            for c in j..j + 2 {
+                for r in j..j + 2 {
+                    let id = if r == c { alpha } else { F::zero() };
+                    let x = if r == c { t[c][r] - a } else { t[c][r] };
+                    u[c][r] = id + x / (two * alpha);
+                }
+            }
+        }
+        // the off-diagonal blocks above: U_ii X + X U_jj = T_ij - Σ_k U_ik U_kj
+        for &(i, p) in blocks[..jb].iter().rev() {
+            let mut a: Mat<F, 4> = identity();
+            let mut b = [F::zero(); 4];
+            for r in 0..p {
+                for c in 0..q {
+                    let e = r * q + c;
+                    b[e] = t[j + c][i + r]
+                        - (i + p..j).fold(F::zero(), |sum, k| sum + u[k][i + r] * u[j + c][k]);
+                    a[e][e] = F::zero();
+                    (0..p).for_each(|s| a[s * q + c][e] += u[i + s][i + r]);
+                    (0..q).for_each(|s| a[r * q + s][e] += u[j + c][j + s]);
+                }
+            }
+            let (lu, perm) = lu(a).ok()?;
+            let x = lu_solve(&lu, &perm, b);
+            for r in 0..p {
+                for c in 0..q {
+                    u[j + c][i + r] = x[r * q + c];
+                }
+            }
+        }
+    }
+    Some(u)
+}
+
+/// Returns `(q, t)` such that `s = q t q^T`, where `s = (a + a^T) / 2`, `q` is orthogonal and
+/// `t` is symmetric tridiagonal, by the Householder reflections.
+pub(crate) fn tridiagonal<F: BaseFloat, const N: usize>(a: Mat<F, N>) -> (Mat<F, N>, Mat<F, N>) {
+    let half = F::one() / (F::one() + F::one());
+    let s = std::array::from_fn(|j| std::array::from_fn(|i| (a[j][i] + a[i][j]) * half));
+    let (q, mut t) = hessenberg(s);
+    for j in 0..N {
+        for i in j + 1..N {
+            t[i][j] = match i == j + 1 {
+                true => (t[i][j] + t[j][i]) * half,
+                false => F::zero(),
+            };
+            t[j][i] = t[i][j];
+        }
+    }
+    (q, t)
+}
+
+/// Returns `(r, q)` such that `a = r q`, where `r` is upper triangular with the non-negative diagonal
+/// and `q` is orthogonal, by [`householder_qr`] of `(J a)^T` with the reversal permutation `J`.
+pub(crate) fn rq<F: BaseFloat, const N: usize>(a: Mat<F, N>) -> (Mat<F, N>, Mat<F, N>) {
+    let at: Mat<F, N> = std::array::from_fn(|j| std::array::from_fn(|i| a[i][N - 1 - j]));
+    let (qt, rt, _, _) = householder_qr(at, false);
+    let r = std::array::from_fn(|j| std::array::from_fn(|i| rt[N - 1 - i][N - 1 - j]));
+    let q = std::array::from_fn(|j| std::array::from_fn(|i| qt[N - 1 - i][j]));
+    (r, q)
+}
+
+/// Returns the numerical rank: the number of the singular values `sigma`, in descending order as given
+/// by [`svd`], greater than `tol` times the maximum one.
+pub(crate) fn numerical_rank<F: BaseFloat, const N: usize>(sigma: &[F; N], tol: F) -> usize {
+    sigma.iter().take_while(|s| **s > tol * sigma[0]).count()
+}
+
+/// Returns the Frobenius norm of `a`, scaled by the maximum absolute value to avoid overflow.
+pub(crate) fn frobenius<F: BaseFloat, const N: usize>(a: &Mat<F, N>) -> F {
+    let max = max_abs(a);
+    match max == F::zero() {
+        true => max,
+        false => {
+            max * F::sqrt(
+                a.iter()
+                    .flatten()
+                    .fold(F::zero(), |sum, x| sum + (*x / max) * (*x / max)),
+            )
+        }
+    }
+}
+
+/// Returns the maximum absolute value of the entries of `a`.
+pub(crate) fn max_abs<F: BaseFloat, const N: usize>(a: &Mat<F, N>) -> F {
+    a.iter()
+        .flatten()
+        .fold(F::zero(), |max, x| F::max(max, F::abs(*x)))
+}
+
+/// Returns the logarithmic norm of `a` for `L^1`: the maximum over the columns `j` of
+/// `a_jj + Σ_{i ≠ j} |a_ij|`. NaN in a column propagates to the result.
+pub(crate) fn log_norm_l1<F: BaseFloat, const N: usize>(a: &Mat<F, N>) -> F {
+    (0..N)
+        .map(|j| {
+            (0..N).fold(F::zero(), |sum, i| match i == j {
+                true => sum + a[j][i],
+                false => sum + F::abs(a[j][i]),
+            })
+        })
+        .max_by(total_cmp)
+        .unwrap()
+}
+
+/// Returns the `l^p` norm of the singular values `sigma` in descending order, scaled by the maximum one
+/// to avoid overflow. `p` is a positive number or infinity.
+pub(crate) fn schatten<F: BaseFloat, const N: usize>(sigma: &[F; N], p: F) -> F {
+    if p == F::infinity() || sigma[0] == F::zero() {
+        return sigma[0];
+    }
+    let sum = sigma
+        .iter()
+        .fold(F::zero(), |sum, s| sum + F::powf(*s / sigma[0], p));
+    sigma[0] * F::powf(sum, F::one() / p)
+}
+
+/// Returns the ratio of the maximum and the minimum of the singular values `sigma` in descending order,
+/// or infinity if the minimum is not greater than `N ε` times the maximum, where the matrix is
+/// numerically singular.
+pub(crate) fn condition_number<F: BaseFloat, const N: usize>(sigma: &[F; N]) -> F {
+    match sigma[N - 1] <= F::from(N).unwrap() * F::epsilon() * sigma[0] {
+        true => F::infinity(),
+        false => sigma[0] / sigma[N - 1],
+    }
+}
+
+/// Returns the Moore–Penrose pseudo inverse of `a` and the [`numerical_rank`] of `a`.
+/// The singular values not counted in the rank are regarded as zero.
+/// If `a` has an infinite or NaN entry, the pseudo inverse is NaN and the rank is zero.
+pub(crate) fn pseudo_inverse<F: BaseFloat, const N: usize>(
+    a: Mat<F, N>,
+    tol: F,
+) -> (Mat<F, N>, usize) {
+    let (u, sigma, v) = svd(a);
+    if sigma[0].is_nan() {
+        return ([[F::nan(); N]; N], 0);
+    }
+    let rank = numerical_rank(&sigma, tol);
+    let p = std::array::from_fn(|j| {
+        std::array::from_fn(|i| {
+            (0..rank).fold(F::zero(), |sum, k| sum + v[k][i] * u[k][j] / sigma[k])
+        })
+    });
+    (p, rank)
+}
+
+/// Returns the `l^p` norm of `x`, scaled by the maximum absolute value to avoid overflow.
+/// `p` is a finite number not less than one.
+fn vector_lp<F: BaseFloat, const N: usize>(x: &[F; N], p: F) -> F {
+    let max = x.iter().fold(F::zero(), |max, x| F::max(max, F::abs(*x)));
+    if max == F::zero() {
+        return max;
+    }
+    let sum = x
+        .iter()
+        .fold(F::zero(), |sum, x| sum + F::powf(F::abs(*x) / max, p));
+    max * F::powf(sum, F::one() / p)
+}
+
+/// Returns the dual vector of `x` with `‖x‖_p = 1`: `sign(x_i) |x_i|^{p - 1}`, whose `l^q` norm is one
+/// for `1/p + 1/q = 1`, and whose inner product with `x` is one.
+fn dual_vector<F: BaseFloat, const N: usize>(x: [F; N], p: F) -> [F; N] {
+    x.map(|x| match x == F::zero() {
+        true => x,
+        false => F::signum(x) * F::powf(F::abs(x), p - F::one()),
+    })
+}
+
+/// Returns the local maximum of `‖a x‖_p / ‖x‖_p` given by Boyd's power method started from `x`, and the
+/// vector `x` attaining it. The estimates are non-decreasing along the iteration.
+fn lp_power_method<F: BaseFloat, const N: usize>(a: &Mat<F, N>, p: F, x: [F; N]) -> (F, [F; N]) {
+    const MAX_ITERATIONS: usize = 100;
+    let q = p / (p - F::one());
+    let tol = F::from(4).unwrap() * F::epsilon();
+    let norm = vector_lp(&x, p);
+    if norm == F::zero() {
+        return (norm, x);
+    }
+    let mut x = x.map(|x| x / norm);
+    let mut est = F::zero();
+    for _ in 0..MAX_ITERATIONS {
+        let y = std::array::from_fn(|i| (0..N).fold(F::zero(), |sum, j| sum + a[j][i] * x[j]));
+        est = vector_lp(&y, p);
+        if est == F::zero() {
+            break;
+        }
+        let w = dual_vector(y.map(|y| y / est), p);
+        let z: [F; N] = std::array::from_fn(|j| dot(a[j], w));
+        let z_norm = vector_lp(&z, q);
+        if z_norm <= est * (F::one() + tol) {
+            break;
+        }
+        x = dual_vector(z.map(|z| z / z_norm), q);
+    }
+    (est, x)
+}
+
+/// Returns an estimate of the `L^p` operator norm of `a` for `1 < p < ∞` by Boyd's power method in the
+/// form of N. J. Higham, "Estimating the matrix p-norm", Numer. Math. 62 (1992).
+///
+/// The iteration is started from the unit vectors, the sign vectors and the right singular vectors, and
+/// also from the vectors dual to the local maxima of the transpose for the dual exponent, and the maximum
+/// of the local maxima is returned. The start from the unit vectors assures
+/// `est ≤ ‖a‖_p ≤ N^{1 - 1/p} est`.
+pub(crate) fn operator_norm_lp<F: BaseFloat, const N: usize>(a: &Mat<F, N>, p: F) -> F {
+    if check_finite(a).is_err() {
+        return F::nan();
+    }
+    let q = p / (p - F::one());
+    let at: Mat<F, N> = std::array::from_fn(|j| std::array::from_fn(|i| a[i][j]));
+    let (u, _, v) = svd(*a);
+    let starts = |singular_vectors: Mat<F, N>| {
+        let signs = (0..1 << (N - 1)).map(|bits: usize| {
+            std::array::from_fn(|j| match bits >> j & 1 == 1 {
+                true => -F::one(),
+                false => F::one(),
+            })
+        });
+        identity::<F, N>()
+            .into_iter()
+            .chain(signs)
+            .chain(singular_vectors)
+    };
+    let duals = starts(u).map(|w| {
+        let (_, w) = lp_power_method(&at, q, w);
+        let z: [F; N] = std::array::from_fn(|j| dot(a[j], w));
+        let norm = vector_lp(&z, q);
+        match norm == F::zero() {
+            true => z,
+            false => dual_vector(z.map(|z| z / norm), q),
+        }
+    });
+    starts(v).chain(duals).fold(F::zero(), |best, x| {
+        F::max(best, lp_power_method(a, p, x).0)
+    })
+}
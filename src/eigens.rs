@@ -1,26 +1,26 @@
 use crate::*;
 
-impl<F: BaseFloat> EigenValues for Matrix2<F> {
-    type EigenValues = [Complex<F>; 2];
-    fn eigenvalues(self) -> [Complex<F>; 2] {
-        solver::solve_quadratic(-self.trace(), self.determinant())
+impl<F: BaseFloat> CharacteristicPolynomial for Matrix2<F> {
+    type Coefficients = [F; 2];
+    fn characteristic_polynomial(self) -> [F; 2] {
+        [-self.trace(), self.determinant()]
     }
 }
 
-impl<F: BaseFloat> EigenValues for Matrix3<F> {
-    type EigenValues = [Complex<F>; 3];
-    fn eigenvalues(self) -> [Complex<F>; 3] {
+impl<F: BaseFloat> CharacteristicPolynomial for Matrix3<F> {
+    type Coefficients = [F; 3];
+    fn characteristic_polynomial(self) -> [F; 3] {
         let poly2 = self[0][0] * self[1][1] + self[0][0] * self[2][2] + self[1][1] * self[2][2]
             - self[0][1] * self[1][0]
             - self[1][2] * self[2][1]
             - self[0][2] * self[2][0];
-        solver::solve_cubic(-self.trace(), poly2, -self.determinant())
+        [-self.trace(), poly2, -self.determinant()]
     }
 }
 
-impl<F: BaseFloat> EigenValues for Matrix4<F> {
-    type EigenValues = [Complex<F>; 4];
-    fn eigenvalues(self) -> [Complex<F>; 4] {
+impl<F: BaseFloat> CharacteristicPolynomial for Matrix4<F> {
+    type Coefficients = [F; 4];
+    fn characteristic_polynomial(self) -> [F; 4] {
         let poly2 = self[0][0] * self[1][1]
             + self[0][0] * self[2][2]
             + self[0][0] * self[3][3]
@@ -49,7 +49,157 @@ impl<F: BaseFloat> EigenValues for Matrix4<F> {
                 * self[2][3]
             + Matrix3::from_cols(self[0].truncate(), self[1].truncate(), self[2].truncate())
                 .determinant();
-        solver::solve_quartic(-self.trace(), poly2, -poly3, self.determinant())
+        [-self.trace(), poly2, -poly3, self.determinant()]
+    }
+}
+
+impl<F: BaseFloat> EigenValues for Matrix2<F> {
+    type EigenValues = [Complex<F>; 2];
+    fn eigenvalues(self) -> [Complex<F>; 2] {
+        let [a, b] = self.characteristic_polynomial();
+        solver::solve_quadratic(a, b)
+    }
+    fn eigenvalues_by_schur(self) -> [Complex<F>; 2] {
+        match dense::schur(self.into()) {
+            Ok((_, t)) => dense::schur_eigenvalues(&t),
+            Err(_) => [Complex::new(F::nan(), F::nan()); 2],
+        }
+    }
+}
+
+impl<F: BaseFloat> EigenValues for Matrix3<F> {
+    type EigenValues = [Complex<F>; 3];
+    fn eigenvalues(self) -> [Complex<F>; 3] {
+        let [a, b, c] = self.characteristic_polynomial();
+        solver::solve_cubic(a, b, c)
+    }
+    fn eigenvalues_by_schur(self) -> [Complex<F>; 3] {
+        match dense::schur(self.into()) {
+            Ok((_, t)) => dense::schur_eigenvalues(&t),
+            Err(_) => [Complex::new(F::nan(), F::nan()); 3],
+        }
+    }
+}
+
+impl<F: BaseFloat> EigenValues for Matrix4<F> {
+    type EigenValues = [Complex<F>; 4];
+    fn eigenvalues(self) -> [Complex<F>; 4] {
+        let [a, b, c, d] = self.characteristic_polynomial();
+        solver::solve_quartic(a, b, c, d)
+    }
+    fn eigenvalues_by_schur(self) -> [Complex<F>; 4] {
+        match dense::schur(self.into()) {
+            Ok((_, t)) => dense::schur_eigenvalues(&t),
+            Err(_) => [Complex::new(F::nan(), F::nan()); 4],
+        }
+    }
+}
+
+impl<F: BaseFloat> EigenVectors for Matrix2<F> {
+    type EigenVectors = [(Complex<F>, Option<Vector2<Complex<F>>>); 2];
+    fn eigenvectors(self) -> Self::EigenVectors {
+        dense::eigenvectors(self.into(), self.eigenvalues()).map(|(e, v)| (e, v.map(Vector2::from)))
+    }
+}
+
+impl<F: BaseFloat> EigenVectors for Matrix3<F> {
+    type EigenVectors = [(Complex<F>, Option<Vector3<Complex<F>>>); 3];
+    fn eigenvectors(self) -> Self::EigenVectors {
+        dense::eigenvectors(self.into(), self.eigenvalues()).map(|(e, v)| (e, v.map(Vector3::from)))
+    }
+}
+
+impl<F: BaseFloat> EigenVectors for Matrix4<F> {
+    type EigenVectors = [(Complex<F>, Option<Vector4<Complex<F>>>); 4];
+    fn eigenvectors(self) -> Self::EigenVectors {
+        dense::eigenvectors(self.into(), self.eigenvalues()).map(|(e, v)| (e, v.map(Vector4::from)))
+    }
+}
+
+impl<F: BaseFloat> Spectral for Matrix2<F> {
+    fn spectral_radius(self) -> F {
+        let eigens = self.eigenvalues_by_schur();
+        eigens
+            .iter()
+            .map(|e| e.norm())
+            .max_by(dense::total_cmp)
+            .unwrap()
+    }
+    fn spectral_abscissa(self) -> F {
+        let eigens = self.eigenvalues_by_schur();
+        eigens
+            .iter()
+            .map(|e| e.re)
+            .max_by(dense::total_cmp)
+            .unwrap()
+    }
+    fn numerical_abscissa(self) -> F {
+        self.log_norm_l2()
+    }
+}
+
+impl<F: BaseFloat> Spectral for Matrix3<F> {
+    fn spectral_radius(self) -> F {
+        let eigens = self.eigenvalues_by_schur();
+        eigens
+            .iter()
+            .map(|e| e.norm())
+            .max_by(dense::total_cmp)
+            .unwrap()
+    }
+    fn spectral_abscissa(self) -> F {
+        let eigens = self.eigenvalues_by_schur();
+        eigens
+            .iter()
+            .map(|e| e.re)
+            .max_by(dense::total_cmp)
+            .unwrap()
+    }
+    fn numerical_abscissa(self) -> F {
+        self.log_norm_l2()
+    }
+}
+
+impl<F: BaseFloat> Spectral for Matrix4<F> {
+    fn spectral_radius(self) -> F {
+        let eigens = self.eigenvalues_by_schur();
+        eigens
+            .iter()
+            .map(|e| e.norm())
+            .max_by(dense::total_cmp)
+            .unwrap()
+    }
+    fn spectral_abscissa(self) -> F {
+        let eigens = self.eigenvalues_by_schur();
+        eigens
+            .iter()
+            .map(|e| e.re)
+            .max_by(dense::total_cmp)
+            .unwrap()
+    }
+    fn numerical_abscissa(self) -> F {
+        self.log_norm_l2()
+    }
+}
+
+impl<F: BaseFloat> SymmetricEigen for Matrix2<F> {
+    fn symmetric_eigen(self) -> (Vector2<F>, Matrix2<F>) {
+        let (values, vectors) = dense::symmetric_eigen(self.into());
+        (values.into(), vectors.into())
+    }
+}
+
+impl<F: BaseFloat> SymmetricEigen for Matrix3<F> {
+    fn symmetric_eigen(self) -> (Vector3<F>, Matrix3<F>) {
+        let (values, vectors) = dense::symmetric_eigen(self.into());
+        (values.into(), vectors.into())
+    }
+}
+
+impl<F: BaseFloat> SymmetricEigen for Matrix4<F> {
+    fn symmetric_eigen(self) -> (Vector4<F>, Matrix4<F>) {
+        let (values, vectors) = dense::symmetric_eigen(self.into());
+        (values.into(), vectors.into())
     }
 }
 
@@ -151,3 +301,185 @@ impl<F: BaseFloat> OperatorNorm for Matrix4<F> {
         ))
     }
 }
+
+impl<F: BaseFloat> LogarithmicNorm for Matrix2<F> {
+    fn log_norm_l1(self) -> F {
+        dense::log_norm_l1(&self.into())
+    }
+    fn log_norm_l2(self) -> F {
+        dense::symmetric_eigen(self.into()).0[1]
+    }
+    fn log_norm_linf(self) -> F {
+        dense::log_norm_l1(&self.transpose().into())
+    }
+}
+
+impl<F: BaseFloat> LogarithmicNorm for Matrix3<F> {
+    fn log_norm_l1(self) -> F {
+        dense::log_norm_l1(&self.into())
+    }
+    fn log_norm_l2(self) -> F {
+        dense::symmetric_eigen(self.into()).0[2]
+    }
+    fn log_norm_linf(self) -> F {
+        dense::log_norm_l1(&self.transpose().into())
+    }
+}
+
+impl<F: BaseFloat> LogarithmicNorm for Matrix4<F> {
+    fn log_norm_l1(self) -> F {
+        dense::log_norm_l1(&self.into())
+    }
+    fn log_norm_l2(self) -> F {
+        dense::symmetric_eigen(self.into()).0[3]
+    }
+    fn log_norm_linf(self) -> F {
+        dense::log_norm_l1(&self.transpose().into())
+    }
+}
+
+impl<F: BaseFloat> OperatorNormLp for Matrix2<F> {
+    fn norm_lp(self, p: F) -> F {
+        norm_lp_by_power_method(self, p)
+    }
+}
+
+impl<F: BaseFloat> OperatorNormLp for Matrix3<F> {
+    fn norm_lp(self, p: F) -> F {
+        norm_lp_by_power_method(self, p)
+    }
+}
+
+impl<F: BaseFloat> OperatorNormLp for Matrix4<F> {
+    fn norm_lp(self, p: F) -> F {
+        norm_lp_by_power_method(self, p)
+    }
+}
+
+/// [`OperatorNormLp::norm_lp`]: exact for `p = 1, 2, ∞`, and estimated by the power method otherwise
+fn norm_lp_by_power_method<F, M, const N: usize>(m: M, p: F) -> F
+where
+    F: BaseFloat,
+    M: OperatorNorm<Scalar = F> + Into<[[F; N]; N]>,
+{
+    if p.is_nan() || p < F::one() {
+        F::nan()
+    } else if p == F::one() {
+        m.norm_l1()
+    } else if p == F::infinity() {
+        m.norm_linf()
+    } else if p == F::from(2).unwrap() {
+        dense::svd(m.into()).1[0]
+    } else {
+        dense::operator_norm_lp(&m.into(), p)
+    }
+}
+
+impl<F: BaseFloat> ConditionNumber for Matrix2<F> {
+    fn cond_l1(self) -> F {
+        condition_number_by_inverse(self, Matrix2::norm_l1)
+    }
+    fn cond_l2(self) -> F {
+        let (_, sigma, _) = dense::svd(self.into());
+        dense::condition_number(&sigma)
+    }
+    fn cond_linf(self) -> F {
+        condition_number_by_inverse(self, Matrix2::norm_linf)
+    }
+}
+
+impl<F: BaseFloat> ConditionNumber for Matrix3<F> {
+    fn cond_l1(self) -> F {
+        condition_number_by_inverse(self, Matrix3::norm_l1)
+    }
+    fn cond_l2(self) -> F {
+        let (_, sigma, _) = dense::svd(self.into());
+        dense::condition_number(&sigma)
+    }
+    fn cond_linf(self) -> F {
+        condition_number_by_inverse(self, Matrix3::norm_linf)
+    }
+}
+
+impl<F: BaseFloat> ConditionNumber for Matrix4<F> {
+    fn cond_l1(self) -> F {
+        condition_number_by_inverse(self, Matrix4::norm_l1)
+    }
+    fn cond_l2(self) -> F {
+        let (_, sigma, _) = dense::svd(self.into());
+        dense::condition_number(&sigma)
+    }
+    fn cond_linf(self) -> F {
+        condition_number_by_inverse(self, Matrix4::norm_linf)
+    }
+}
+
+/// `norm(m) norm(m^{-1})`, infinity if `m` is singular, or NaN if `m` is not finite
+fn condition_number_by_inverse<F, M>(m: M, norm: fn(M) -> F) -> F
+where
+    F: BaseFloat,
+    M: LuDecomposition + SquareMatrix<Scalar = F>,
+{
+    match m.solve_matrix(M::identity()) {
+        Ok(inv) => norm(m) * norm(inv),
+        Err(DecompositionError::RankDeficient { .. }) => F::infinity(),
+        Err(_) => F::nan(),
+    }
+}
+
+impl<F: BaseFloat> EntrywiseNorm for Matrix2<F> {
+    fn norm_frobenius(self) -> F {
+        dense::frobenius(&self.into())
+    }
+    fn norm_max(self) -> F {
+        dense::max_abs(&self.into())
+    }
+}
+
+impl<F: BaseFloat> SchattenNorm for Matrix2<F> {
+    fn norm_nuclear(self) -> F {
+        self.norm_schatten(F::one())
+    }
+    fn norm_schatten(self, p: F) -> F {
+        let (_, sigma, _) = dense::svd(self.into());
+        dense::schatten(&sigma, p)
+    }
+}
+
+impl<F: BaseFloat> EntrywiseNorm for Matrix3<F> {
+    fn norm_frobenius(self) -> F {
+        dense::frobenius(&self.into())
+    }
+    fn norm_max(self) -> F {
+        dense::max_abs(&self.into())
+    }
+}
+
+impl<F: BaseFloat> SchattenNorm for Matrix3<F> {
+    fn norm_nuclear(self) -> F {
+        self.norm_schatten(F::one())
+    }
+    fn norm_schatten(self, p: F) -> F {
+        let (_, sigma, _) = dense::svd(self.into());
+        dense::schatten(&sigma, p)
+    }
+}
+
+impl<F: BaseFloat> EntrywiseNorm for Matrix4<F> {
+    fn norm_frobenius(self) -> F {
+        dense::frobenius(&self.into())
+    }
+    fn norm_max(self) -> F {
+        dense::max_abs(&self.into())
+    }
+}
+
+impl<F: BaseFloat> SchattenNorm for Matrix4<F> {
+    fn norm_nuclear(self) -> F {
+        self.norm_schatten(F::one())
+    }
+    fn norm_schatten(self, p: F) -> F {
+        let (_, sigma, _) = dense::svd(self.into());
+        dense::schatten(&sigma, p)
+    }
+}
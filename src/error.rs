@@ -0,0 +1,50 @@
+use std::fmt::{Debug, Display, Formatter, Result};
+
+/// errors of the decompositions of matrices
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecompositionError<F> {
+    /// The matrix is (numerically) rank-deficient.
+    RankDeficient {
+        /// the index of the column at which the rank deficiency is detected
+        column: usize,
+        /// the magnitude of the vanishing pivot, orthogonal component or singular value
+        magnitude: F,
+    },
+    /// The symmetric matrix is not positive definite.
+    NotPositiveDefinite {
+        /// the index of the failed pivot
+        pivot: usize,
+        /// the value of the failed pivot
+        value: F,
+    },
+    /// The matrix has an infinite or NaN entry.
+    NonFinite,
+    /// The bottom row of the transform is not `(0, 0, 0, 1)`.
+    NotAffine,
+    /// The iteration did not converge.
+    NotConverged,
+}
+
+impl<F: Debug> Display for DecompositionError<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::RankDeficient { column, magnitude } => write!(
+                f,
+                "rank-deficient matrix: the magnitude at the column {column} is {magnitude:?}."
+            ),
+            Self::NotPositiveDefinite { pivot, value } => write!(
+                f,
+                "not positive definite matrix: the pivot {pivot} is {value:?}."
+            ),
+            Self::NonFinite => write!(f, "non-finite matrix: an entry is infinite or NaN."),
+            Self::NotAffine => write!(
+                f,
+                "not affine transform: the bottom row is not (0, 0, 0, 1)."
+            ),
+            Self::NotConverged => write!(f, "not converged iteration."),
+        }
+    }
+}
+
+impl<F: Debug> std::error::Error for DecompositionError<F> {}
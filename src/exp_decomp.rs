@@ -1,106 +1,560 @@
 use crate::*;
 
-impl<F: BaseFloat> Exponential for Matrix2<F> {}
-impl<F: BaseFloat> Exponential for Matrix3<F> {}
-impl<F: BaseFloat> Exponential for Matrix4<F> {}
+impl<F: BaseFloat> Exponential for Matrix2<F> {
+    fn exp(self) -> Self {
+        scaling_and_squaring(self)
+    }
+}
+impl<F: BaseFloat> Exponential for Matrix3<F> {
+    fn exp(self) -> Self {
+        scaling_and_squaring(self)
+    }
+}
+impl<F: BaseFloat> Exponential for Matrix4<F> {
+    fn exp(self) -> Self {
+        scaling_and_squaring(self)
+    }
+}
 
-impl<F: BaseFloat> Decomposition for Matrix2<F> {
-    fn iwasawa_decomposition(self) -> Option<(Self, Self, Self)> {
-        let v0 = self[0];
-        let mag0 = dot(v0, v0);
-        if mag0 <= F::zero() {
-            return None;
-        }
-        let n0 = dot(self[1], v0) / mag0;
-        let v1 = self[1] - v0 * n0;
-        let mag1 = dot(v1, v1);
-        if mag1 <= F::zero() {
-            return None;
+/// pairs of theta_m and the coefficients of the Padé approximants of degree m = 3, 5, 7, 9
+const PADE: [(f64, &[f64]); 4] = [
+    (1.495585217958292e-2, &[120.0, 60.0, 12.0, 1.0]),
+    (
+        2.53939833006323e-1,
+        &[30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0],
+    ),
+    (
+        9.504178996162932e-1,
+        &[
+            17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+        ],
+    ),
+    (
+        2.097847961257068e0,
+        &[
+            17643225600.0,
+            8821612800.0,
+            2075673600.0,
+            302702400.0,
+            30270240.0,
+            2162160.0,
+            110880.0,
+            3960.0,
+            90.0,
+            1.0,
+        ],
+    ),
+];
+
+/// theta_13 and the coefficients of the Padé approximant of degree 13
+const PADE13: (f64, [f64; 14]) = (
+    5.371920351148152e0,
+    [
+        64764752532480000.0,
+        32382376266240000.0,
+        7771770303897600.0,
+        1187353796428800.0,
+        129060195264000.0,
+        10559470521600.0,
+        670442572800.0,
+        33522128640.0,
+        1323241920.0,
+        40840800.0,
+        960960.0,
+        16380.0,
+        182.0,
+        1.0,
+    ],
+);
+
+/// the scaling and squaring method by N. J. Higham,
+/// "The scaling and squaring method for the matrix exponential revisited", 2005.
+fn scaling_and_squaring<F, M>(m: M) -> M
+where
+    F: BaseFloat,
+    M: OperatorNorm + SquareMatrix<Scalar = F>,
+{
+    let norm = m.norm_l1();
+    if !norm.is_finite() {
+        return m * F::nan();
+    }
+    // The coefficients are normalized so that the constant terms are one.
+    let coef = |b: &[f64], k: usize| F::from(b[k] / b[0]).unwrap();
+    let id = M::identity();
+    let m2 = m * m;
+    for (theta, b) in PADE {
+        if norm <= F::from(theta).unwrap() {
+            let (mut u, mut v, mut pow) = (M::zero(), M::zero(), id);
+            for k in (0..b.len()).step_by(2) {
+                v = v + pow * coef(b, k);
+                u = u + pow * coef(b, k + 1);
+                pow = pow * m2;
+            }
+            return pade_quotient(m * u, v);
         }
-        let (a0, a1) = (F::sqrt(mag0), F::sqrt(mag1));
-        Some((
-            Matrix2::from_cols(v0 / a0, v1 / a1),
-            Matrix2::from_diagonal(Vector2::new(a0, a1)),
-            Matrix2::new(F::one(), F::zero(), n0, F::one()),
-        ))
     }
+
+    let (theta, b) = PADE13;
+    let s = F::max(F::ceil(F::log2(norm / F::from(theta).unwrap())), F::zero());
+    let s = s.to_i32().unwrap();
+    let two = F::one() + F::one();
+    let a = m / F::powi(two, s);
+    let a2 = m2 / F::powi(two, 2 * s);
+    let a4 = a2 * a2;
+    let a6 = a4 * a2;
+    let u = a
+        * (a6 * (a6 * coef(&b, 13) + a4 * coef(&b, 11) + a2 * coef(&b, 9))
+            + a6 * coef(&b, 7)
+            + a4 * coef(&b, 5)
+            + a2 * coef(&b, 3)
+            + id * coef(&b, 1));
+    let v = a6 * (a6 * coef(&b, 12) + a4 * coef(&b, 10) + a2 * coef(&b, 8))
+        + a6 * coef(&b, 6)
+        + a4 * coef(&b, 4)
+        + a2 * coef(&b, 2)
+        + id;
+    let mut res = pade_quotient(u, v);
+    (0..s).for_each(|_| res = res * res);
+    res
 }
 
-impl<F: BaseFloat> Decomposition for Matrix3<F> {
-    fn iwasawa_decomposition(self) -> Option<(Self, Self, Self)> {
-        let v0 = self[0];
-        let mag0 = dot(v0, v0);
-        if mag0 <= F::zero() {
-            return None;
-        }
-        let n0 = dot(self[1], v0) / mag0;
-        let v1 = self[1] - v0 * n0;
-        let mag1 = dot(v1, v1);
-        if mag1 <= F::zero() {
+/// Returns (V - U)^{-1} (V + U).
+fn pade_quotient<F, M>(u: M, v: M) -> M
+where
+    F: BaseFloat,
+    M: SquareMatrix<Scalar = F>,
+{
+    // The denominator is well-conditioned in the range of the norm where the approximant is used,
+    // and may be singular only by NaN entries, which are propagated.
+    match (v - u).invert() {
+        Some(inv) => inv * (v + u),
+        None => (v + u) * F::nan(),
+    }
+}
+
+impl<F: BaseFloat> Logarithm for Matrix2<F> {
+    fn log(self) -> Option<Self> {
+        logarithm(self)
+    }
+}
+impl<F: BaseFloat> Logarithm for Matrix3<F> {
+    fn log(self) -> Option<Self> {
+        logarithm(self)
+    }
+}
+impl<F: BaseFloat> Logarithm for Matrix4<F> {
+    fn log(self) -> Option<Self> {
+        logarithm(self)
+    }
+}
+
+/// nodes and weights of the Gauss-Legendre quadrature on [0, 1]
+const GAUSS_LEGENDRE: [(f64, f64); 8] = [
+    (0.019855071751231884, 0.05061426814518813),
+    (0.10166676129318664, 0.11119051722668724),
+    (0.2372337950418355, 0.15685332293894363),
+    (0.4082826787521751, 0.181341891689181),
+    (0.591717321247825, 0.181341891689181),
+    (0.7627662049581645, 0.15685332293894363),
+    (0.8983332387068134, 0.11119051722668724),
+    (0.9801449282487681, 0.05061426814518813),
+];
+
+/// the inverse scaling and squaring method on the real Schur form `M = Q T Q^T`, whose square roots
+/// are given by [`dense::schur_sqrt`].
+fn logarithm<F, M, const N: usize>(m: M) -> Option<M>
+where
+    F: BaseFloat,
+    M: OperatorNorm + SquareMatrix<Scalar = F> + From<[[F; N]; N]> + Into<[[F; N]; N]>,
+{
+    let n = F::from(N).unwrap();
+    let tol = n * F::epsilon() * m.norm_l1();
+    let (q, mut t) = dense::schur(m.into()).ok()?;
+    // The eigen values of the Schur form are accurate up to the rounding errors, and the ones
+    // within them from the negative real axis are regarded as on the axis.
+    let non_positive = |e: &Complex<F>| e.re <= F::zero() && F::abs(e.im) <= tol;
+    if dense::schur_eigenvalues(&t).iter().any(non_positive) {
+        return None;
+    }
+    let tol = n * F::sqrt(F::epsilon());
+    // inverse scaling: take square roots until close to the identity
+    let quarter = F::from(0.25).unwrap();
+    let mut scale = F::one();
+    while (M::from(t) - M::identity()).norm_l1() > quarter {
+        if scale > F::from(1u64 << 60).unwrap() {
             return None;
         }
-        let n1 = dot(self[2], v0) / mag0;
-        let n2 = dot(self[2], v1) / mag1;
-        let v2 = self[2] - v0 * n1 - v1 * n2;
-        let mag2 = dot(v2, v2);
-        if mag2 <= F::zero() {
+        let root = dense::schur_sqrt(&t)?;
+        // The residual detects the breakdown, e.g. by a non-finite entry.
+        let u = M::from(root);
+        let res = (u * u - M::from(t)).norm_l1();
+        if res.is_nan() || res > tol * u.norm_l1() * u.norm_l1() {
             return None;
         }
-        let (a0, a1, a2) = (F::sqrt(mag0), F::sqrt(mag1), F::sqrt(mag2));
-        #[rustfmt::skip]
-        let res = Some((
-            Matrix3::from_cols(v0 / a0, v1 / a1, v2 / a2),
-            Matrix3::from_diagonal(Vector3::new(a0, a1, a2)),
-            Matrix3::new(
-                F::one(), F::zero(), F::zero(),
-                n0, F::one(), F::zero(),
-                n1, n2, F::one(),
-            ),
-        ));
-        res
+        t = root;
+        scale = scale + scale;
+    }
+    // diagonal Padé approximant of log(I + X) as Gauss-Legendre quadrature of X (I + tX)^{-1}
+    let x = M::from(t) - M::identity();
+    let res = GAUSS_LEGENDRE.iter().try_fold(M::zero(), |sum, &(t, w)| {
+        let inv = (M::identity() + x * F::from(t).unwrap()).invert()?;
+        Some(sum + x * inv * F::from(w).unwrap())
+    })?;
+    let q = M::from(q);
+    Some(q * res * q.transpose() * scale)
+}
+
+impl<F: BaseFloat> Decomposition for Matrix2<F> {
+    fn iwasawa_decomposition_with_tolerance(
+        self,
+        tol: F,
+    ) -> Result<(Self, Self, Self), DecompositionError<F>> {
+        let (k, a, n) = dense::iwasawa(self.into(), tol)?;
+        Ok((k.into(), Matrix2::from_diagonal(a.into()), n.into()))
+    }
+}
+
+impl<F: BaseFloat> Decomposition for Matrix3<F> {
+    fn iwasawa_decomposition_with_tolerance(
+        self,
+        tol: F,
+    ) -> Result<(Self, Self, Self), DecompositionError<F>> {
+        let (k, a, n) = dense::iwasawa(self.into(), tol)?;
+        Ok((k.into(), Matrix3::from_diagonal(a.into()), n.into()))
     }
 }
 
 impl<F: BaseFloat> Decomposition for Matrix4<F> {
-    fn iwasawa_decomposition(self) -> Option<(Self, Self, Self)> {
-        let v0 = self[0];
-        let mag0 = dot(v0, v0);
-        if mag0 <= F::zero() {
-            return None;
-        }
-        let n0 = dot(self[1], v0) / mag0;
-        let v1 = self[1] - v0 * n0;
-        let mag1 = dot(v1, v1);
-        if mag1 <= F::zero() {
-            return None;
-        }
-        let n1 = dot(self[2], v0) / mag0;
-        let n2 = dot(self[2], v1) / mag1;
-        let v2 = self[2] - v0 * n1 - v1 * n2;
-        let mag2 = dot(v2, v2);
-        if mag2 <= F::zero() {
-            return None;
-        }
-        let n3 = dot(self[3], v0) / mag0;
-        let n4 = dot(self[3], v1) / mag1;
-        let n5 = dot(self[3], v2) / mag2;
-        let v3 = self[3] - v0 * n3 - v1 * n4 - v2 * n5;
-        let mag3 = dot(v3, v3);
-        if mag3 <= F::zero() {
-            return None;
-        }
-        let (a0, a1, a2, a3) = (F::sqrt(mag0), F::sqrt(mag1), F::sqrt(mag2), F::sqrt(mag3));
-        #[rustfmt::skip]
-        let res = Some((
-            Matrix4::from_cols(v0 / a0, v1 / a1, v2 / a2, v3 / a3),
-            Matrix4::from_diagonal(Vector4::new(a0, a1, a2, a3)),
-            Matrix4::new(
-                F::one(), F::zero(), F::zero(), F::zero(),
-                n0, F::one(), F::zero(), F::zero(),
-                n1, n2, F::one(), F::zero(),
-                n3, n4, n5, F::one(),
-            ),
-        ));
-        res
+    fn iwasawa_decomposition_with_tolerance(
+        self,
+        tol: F,
+    ) -> Result<(Self, Self, Self), DecompositionError<F>> {
+        let (k, a, n) = dense::iwasawa(self.into(), tol)?;
+        Ok((k.into(), Matrix4::from_diagonal(a.into()), n.into()))
+    }
+}
+
+impl<F: BaseFloat> SingularValueDecomposition for Matrix2<F> {
+    fn svd(self) -> (Self, Self, Self) {
+        let (u, sigma, v) = dense::svd(self.into());
+        (
+            u.into(),
+            Matrix2::from_diagonal(sigma.into()),
+            Matrix2::from(v).transpose(),
+        )
+    }
+    fn polar_decomposition(self, proper: bool) -> Result<(Self, Self), DecompositionError<F>> {
+        polar_decomposition(self, proper)
+    }
+}
+
+impl<F: BaseFloat> SingularValueDecomposition for Matrix3<F> {
+    fn svd(self) -> (Self, Self, Self) {
+        let (u, sigma, v) = dense::svd(self.into());
+        (
+            u.into(),
+            Matrix3::from_diagonal(sigma.into()),
+            Matrix3::from(v).transpose(),
+        )
+    }
+    fn polar_decomposition(self, proper: bool) -> Result<(Self, Self), DecompositionError<F>> {
+        polar_decomposition(self, proper)
+    }
+}
+
+impl<F: BaseFloat> SingularValueDecomposition for Matrix4<F> {
+    fn svd(self) -> (Self, Self, Self) {
+        let (u, sigma, v) = dense::svd(self.into());
+        (
+            u.into(),
+            Matrix4::from_diagonal(sigma.into()),
+            Matrix4::from(v).transpose(),
+        )
+    }
+    fn polar_decomposition(self, proper: bool) -> Result<(Self, Self), DecompositionError<F>> {
+        polar_decomposition(self, proper)
+    }
+}
+
+impl<F: BaseFloat> QrDecomposition for Matrix2<F> {
+    fn qr(self) -> (Self, Self) {
+        let (q, r, _, _) = dense::householder_qr(self.into(), false);
+        (q.into(), r.into())
+    }
+    fn rq(self) -> (Self, Self) {
+        let (r, q) = dense::rq(self.into());
+        (r.into(), q.into())
+    }
+    fn qr_pivoted(self) -> (Self, Self, Self, usize) {
+        let (q, r, perm, rank) = dense::householder_qr(self.into(), true);
+        let p = dense::permutation_matrix(perm);
+        (q.into(), r.into(), p.into(), rank)
+    }
+}
+
+impl<F: BaseFloat> QrDecomposition for Matrix3<F> {
+    fn qr(self) -> (Self, Self) {
+        let (q, r, _, _) = dense::householder_qr(self.into(), false);
+        (q.into(), r.into())
+    }
+    fn rq(self) -> (Self, Self) {
+        let (r, q) = dense::rq(self.into());
+        (r.into(), q.into())
+    }
+    fn qr_pivoted(self) -> (Self, Self, Self, usize) {
+        let (q, r, perm, rank) = dense::householder_qr(self.into(), true);
+        let p = dense::permutation_matrix(perm);
+        (q.into(), r.into(), p.into(), rank)
+    }
+}
+
+impl<F: BaseFloat> QrDecomposition for Matrix4<F> {
+    fn qr(self) -> (Self, Self) {
+        let (q, r, _, _) = dense::householder_qr(self.into(), false);
+        (q.into(), r.into())
+    }
+    fn rq(self) -> (Self, Self) {
+        let (r, q) = dense::rq(self.into());
+        (r.into(), q.into())
+    }
+    fn qr_pivoted(self) -> (Self, Self, Self, usize) {
+        let (q, r, perm, rank) = dense::householder_qr(self.into(), true);
+        let p = dense::permutation_matrix(perm);
+        (q.into(), r.into(), p.into(), rank)
+    }
+}
+
+impl<F: BaseFloat> CholeskyDecomposition for Matrix2<F> {
+    fn cholesky(self) -> Result<Self, DecompositionError<F>> {
+        dense::cholesky(self.into()).map(Into::into)
+    }
+    fn ldlt(self) -> Result<(Self, Self), DecompositionError<F>> {
+        let (l, d) = dense::ldlt(self.into())?;
+        Ok((l.into(), Matrix2::from_diagonal(d.into())))
+    }
+}
+
+impl<F: BaseFloat> CholeskyDecomposition for Matrix3<F> {
+    fn cholesky(self) -> Result<Self, DecompositionError<F>> {
+        dense::cholesky(self.into()).map(Into::into)
+    }
+    fn ldlt(self) -> Result<(Self, Self), DecompositionError<F>> {
+        let (l, d) = dense::ldlt(self.into())?;
+        Ok((l.into(), Matrix3::from_diagonal(d.into())))
+    }
+}
+
+impl<F: BaseFloat> CholeskyDecomposition for Matrix4<F> {
+    fn cholesky(self) -> Result<Self, DecompositionError<F>> {
+        dense::cholesky(self.into()).map(Into::into)
+    }
+    fn ldlt(self) -> Result<(Self, Self), DecompositionError<F>> {
+        let (l, d) = dense::ldlt(self.into())?;
+        Ok((l.into(), Matrix4::from_diagonal(d.into())))
+    }
+}
+
+impl<F: BaseFloat> SchurDecomposition for Matrix2<F> {
+    fn schur(self) -> Result<(Self, Self), DecompositionError<F>> {
+        let (q, t) = dense::schur(self.into())?;
+        Ok((q.into(), t.into()))
+    }
+    fn hessenberg(self) -> (Self, Self) {
+        let (q, h) = dense::hessenberg(self.into());
+        (q.into(), h.into())
+    }
+    fn tridiagonal(self) -> (Self, Self) {
+        let (q, t) = dense::tridiagonal(self.into());
+        (q.into(), t.into())
+    }
+}
+
+impl<F: BaseFloat> SchurDecomposition for Matrix3<F> {
+    fn schur(self) -> Result<(Self, Self), DecompositionError<F>> {
+        let (q, t) = dense::schur(self.into())?;
+        Ok((q.into(), t.into()))
+    }
+    fn hessenberg(self) -> (Self, Self) {
+        let (q, h) = dense::hessenberg(self.into());
+        (q.into(), h.into())
+    }
+    fn tridiagonal(self) -> (Self, Self) {
+        let (q, t) = dense::tridiagonal(self.into());
+        (q.into(), t.into())
+    }
+}
+
+impl<F: BaseFloat> SchurDecomposition for Matrix4<F> {
+    fn schur(self) -> Result<(Self, Self), DecompositionError<F>> {
+        let (q, t) = dense::schur(self.into())?;
+        Ok((q.into(), t.into()))
+    }
+    fn hessenberg(self) -> (Self, Self) {
+        let (q, h) = dense::hessenberg(self.into());
+        (q.into(), h.into())
+    }
+    fn tridiagonal(self) -> (Self, Self) {
+        let (q, t) = dense::tridiagonal(self.into());
+        (q.into(), t.into())
+    }
+}
+
+impl<F: BaseFloat> LuDecomposition for Matrix2<F> {
+    fn lu(self) -> Result<(Self, Self, Self), DecompositionError<F>> {
+        let (lu, perm) = dense::lu(self.into())?;
+        let (l, u) = dense::split_lu(lu);
+        let p = Matrix2::from(dense::permutation_matrix(perm)).transpose();
+        Ok((p, l.into(), u.into()))
+    }
+    fn solve(self, b: Vector2<F>) -> Result<Vector2<F>, DecompositionError<F>> {
+        let (lu, perm) = dense::lu(self.into())?;
+        Ok(dense::lu_solve(&lu, &perm, b.into()).into())
+    }
+    fn solve_matrix(self, b: Self) -> Result<Self, DecompositionError<F>> {
+        let (lu, perm) = dense::lu(self.into())?;
+        let b: [[F; 2]; 2] = b.into();
+        Ok(b.map(|b| dense::lu_solve(&lu, &perm, b)).into())
+    }
+}
+
+impl<F: BaseFloat> LuDecomposition for Matrix3<F> {
+    fn lu(self) -> Result<(Self, Self, Self), DecompositionError<F>> {
+        let (lu, perm) = dense::lu(self.into())?;
+        let (l, u) = dense::split_lu(lu);
+        let p = Matrix3::from(dense::permutation_matrix(perm)).transpose();
+        Ok((p, l.into(), u.into()))
+    }
+    fn solve(self, b: Vector3<F>) -> Result<Vector3<F>, DecompositionError<F>> {
+        let (lu, perm) = dense::lu(self.into())?;
+        Ok(dense::lu_solve(&lu, &perm, b.into()).into())
+    }
+    fn solve_matrix(self, b: Self) -> Result<Self, DecompositionError<F>> {
+        let (lu, perm) = dense::lu(self.into())?;
+        let b: [[F; 3]; 3] = b.into();
+        Ok(b.map(|b| dense::lu_solve(&lu, &perm, b)).into())
+    }
+}
+
+impl<F: BaseFloat> LuDecomposition for Matrix4<F> {
+    fn lu(self) -> Result<(Self, Self, Self), DecompositionError<F>> {
+        let (lu, perm) = dense::lu(self.into())?;
+        let (l, u) = dense::split_lu(lu);
+        let p = Matrix4::from(dense::permutation_matrix(perm)).transpose();
+        Ok((p, l.into(), u.into()))
+    }
+    fn solve(self, b: Vector4<F>) -> Result<Vector4<F>, DecompositionError<F>> {
+        let (lu, perm) = dense::lu(self.into())?;
+        Ok(dense::lu_solve(&lu, &perm, b.into()).into())
+    }
+    fn solve_matrix(self, b: Self) -> Result<Self, DecompositionError<F>> {
+        let (lu, perm) = dense::lu(self.into())?;
+        let b: [[F; 4]; 4] = b.into();
+        Ok(b.map(|b| dense::lu_solve(&lu, &perm, b)).into())
+    }
+}
+
+impl<F: BaseFloat> PseudoInverse for Matrix2<F> {
+    fn pseudo_inverse(self, tol: F) -> (Self, usize) {
+        let (p, rank) = dense::pseudo_inverse(self.into(), tol);
+        (p.into(), rank)
+    }
+}
+
+impl<F: BaseFloat> PseudoInverse for Matrix3<F> {
+    fn pseudo_inverse(self, tol: F) -> (Self, usize) {
+        let (p, rank) = dense::pseudo_inverse(self.into(), tol);
+        (p.into(), rank)
+    }
+}
+
+impl<F: BaseFloat> PseudoInverse for Matrix4<F> {
+    fn pseudo_inverse(self, tol: F) -> (Self, usize) {
+        let (p, rank) = dense::pseudo_inverse(self.into(), tol);
+        (p.into(), rank)
+    }
+}
+
+impl<F: BaseFloat> FundamentalSubspaces for Matrix2<F> {
+    fn rank(self, tol: F) -> usize {
+        let (_, sigma, _) = dense::svd(self.into());
+        dense::numerical_rank(&sigma, tol)
+    }
+    fn null_space(self, tol: F) -> Vec<Vector2<F>> {
+        let (_, sigma, v) = dense::svd(self.into());
+        let rank = dense::numerical_rank(&sigma, tol);
+        v[rank..].iter().map(|v| Vector2::from(*v)).collect()
+    }
+    fn column_space(self, tol: F) -> Vec<Vector2<F>> {
+        let (u, sigma, _) = dense::svd(self.into());
+        let rank = dense::numerical_rank(&sigma, tol);
+        u[..rank].iter().map(|u| Vector2::from(*u)).collect()
+    }
+}
+
+impl<F: BaseFloat> FundamentalSubspaces for Matrix3<F> {
+    fn rank(self, tol: F) -> usize {
+        let (_, sigma, _) = dense::svd(self.into());
+        dense::numerical_rank(&sigma, tol)
+    }
+    fn null_space(self, tol: F) -> Vec<Vector3<F>> {
+        let (_, sigma, v) = dense::svd(self.into());
+        let rank = dense::numerical_rank(&sigma, tol);
+        v[rank..].iter().map(|v| Vector3::from(*v)).collect()
+    }
+    fn column_space(self, tol: F) -> Vec<Vector3<F>> {
+        let (u, sigma, _) = dense::svd(self.into());
+        let rank = dense::numerical_rank(&sigma, tol);
+        u[..rank].iter().map(|u| Vector3::from(*u)).collect()
+    }
+}
+
+impl<F: BaseFloat> FundamentalSubspaces for Matrix4<F> {
+    fn rank(self, tol: F) -> usize {
+        let (_, sigma, _) = dense::svd(self.into());
+        dense::numerical_rank(&sigma, tol)
+    }
+    fn null_space(self, tol: F) -> Vec<Vector4<F>> {
+        let (_, sigma, v) = dense::svd(self.into());
+        let rank = dense::numerical_rank(&sigma, tol);
+        v[rank..].iter().map(|v| Vector4::from(*v)).collect()
+    }
+    fn column_space(self, tol: F) -> Vec<Vector4<F>> {
+        let (u, sigma, _) = dense::svd(self.into());
+        let rank = dense::numerical_rank(&sigma, tol);
+        u[..rank].iter().map(|u| Vector4::from(*u)).collect()
+    }
+}
+
+pub(crate) fn least_squares<F, M>(m: M, b: M::ColumnRow) -> (M::ColumnRow, usize)
+where
+    F: BaseFloat,
+    M: PseudoInverse + SquareMatrix<Scalar = F>,
+{
+    let n = <M::ColumnRow as Array>::len();
+    let (p, rank) = m.pseudo_inverse(F::from(n).unwrap() * F::epsilon());
+    (p * b, rank)
+}
+
+fn polar_decomposition<F, M, const N: usize>(
+    m: M,
+    proper: bool,
+) -> Result<(M, M), DecompositionError<F>>
+where
+    F: BaseFloat,
+    M: SingularValueDecomposition + SquareMatrix<Scalar = F> + Into<[[F; N]; N]>,
+{
+    dense::check_finite(&m.into())?;
+    let (mut u, mut sigma, vt) = m.svd();
+    let tol = F::from(N).unwrap() * F::epsilon() * sigma[0][0];
+    if let Some(column) = (0..N).find(|&j| sigma[j][j] <= tol) {
+        return Err(DecompositionError::RankDeficient {
+            column,
+            magnitude: sigma[column][column],
+        });
+    }
+    if proper && (u * vt).determinant() < F::zero() {
+        u[N - 1] = u[N - 1] * -F::one();
+        sigma[N - 1][N - 1] = -sigma[N - 1][N - 1];
     }
+    Ok((u * vt, vt.transpose() * sigma * vt))
 }
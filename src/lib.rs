@@ -18,8 +18,18 @@ pub use cgmath;
 use cgmath::*;
 use num_complex::Complex;
 
+mod affine;
+mod dense;
 mod eigens;
+mod error;
 mod exp_decomp;
+mod tensor;
+pub use affine::AffineComponents;
+pub use error::DecompositionError;
+/// decomposition of camera projection matrices.
+pub mod camera;
+/// closed forms of exponentials and logarithms of rotations and rigid motions.
+pub mod lie;
 /// solvers for low dimensional algebraic equations.
 pub mod solver;
 
@@ -45,6 +55,195 @@ pub trait EigenValues: VectorSpace {
     /// assert!(Complex::norm(eigens[1] - 2.0) < EPS);
     /// ```
     fn eigenvalues(self) -> Self::EigenValues;
+    /// calculate eigen values from the real Schur form given by [`SchurDecomposition::schur`].
+    ///
+    /// [`EigenValues::eigenvalues`] solves the [characteristic polynomial](CharacteristicPolynomial),
+    /// which is fast but may lose accuracy for non-normal matrices. This method is slower, but backward
+    /// stable. If the Schur decomposition fails, all the eigen values are NaN.
+    ///
+    /// The default implementation returns [`EigenValues::eigenvalues`]. The implementations for
+    /// `Matrix2`, `Matrix3` and `Matrix4` use the Schur form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// use num_complex::Complex;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// // strongly non-normal matrix with the eigen values 1, 2 and 3
+    /// let mat = Matrix3::new(1.0, 0.0, 0.0, 1.0e6, 2.0, 0.0, 0.0, 1.0e6, 3.0);
+    /// let mut eigens = mat.eigenvalues_by_schur();
+    /// eigens.sort_by(|x, y| x.re.partial_cmp(&y.re).unwrap());
+    /// assert!(Complex::norm(eigens[0] - 1.0) < EPS);
+    /// assert!(Complex::norm(eigens[1] - 2.0) < EPS);
+    /// assert!(Complex::norm(eigens[2] - 3.0) < EPS);
+    /// ```
+    fn eigenvalues_by_schur(self) -> Self::EigenValues {
+        self.eigenvalues()
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// coefficients of the characteristic polynomial
+pub trait CharacteristicPolynomial: SquareMatrix
+where
+    Self::Scalar: BaseFloat,
+{
+    /// the type of the array of coefficients
+    type Coefficients;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns the coefficients $[c_1, \dots, c_n]$ of the characteristic polynomial
+    /// $$
+    /// \det(x I - M) = x^n + c_1 x^{n - 1} + \cdots + c_{n - 1} x + c_n,
+    /// $$
+    /// where $c_k$ is $(-1)^k$ times the sum of the principal $k \times k$ minors; in particular,
+    /// $c_1 = -\mathrm{tr} M$ and $c_n = (-1)^n \det M$. The order is the same as the arguments of
+    /// the functions in [`solver`], which [`EigenValues::eigenvalues`] solves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    ///
+    /// // eigen values 1, 2, 3: (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+    /// let mat = Matrix3::new(1.0, 0.0, 0.0, 4.0, 2.0, 0.0, 5.0, 6.0, 3.0);
+    /// assert_eq!(mat.characteristic_polynomial(), [-6.0, 11.0, -6.0]);
+    ///
+    /// // Cayley–Hamilton theorem: M^2 + c_1 M + c_2 I = O
+    /// let mat = Matrix2::new(1.0, 3.0, -2.0, 4.0);
+    /// let [c1, c2] = mat.characteristic_polynomial();
+    /// assert_eq!(mat * mat + mat * c1 + Matrix2::identity() * c2, Matrix2::zero());
+    /// ```
+    fn characteristic_polynomial(self) -> Self::Coefficients;
+}
+
+/// extension for eigen vectors
+pub trait EigenVectors: EigenValues {
+    /// the type of the array of pairs of an eigen value and an eigen vector
+    type EigenVectors;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// calculate pairs of eigen values and eigen vectors.
+    ///
+    /// The eigen values are the same as [`EigenValues::eigenvalues`], in the same order.
+    /// Each eigen vector is normalized, and its component with the maximum modulus is real positive.
+    /// Eigen values closer than $\varepsilon^{1/3}$ relative to the magnitude of the matrix may be
+    /// repeated ones, and if the eigen space has more dimensions, they are paired with an orthonormal basis
+    /// of it.
+    /// If the matrix is defective, i.e. an eigen space is smaller than the multiplicity
+    /// of the eigen value, the vectors lacking in the basis are `None`.
+    /// If the matrix has an infinite or NaN entry, all the vectors are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// use num_complex::Complex;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix2::new(4.0, -2.0, 3.0, -1.0);
+    /// mat.eigenvectors().into_iter().for_each(|(e, v)| {
+    ///     let v = v.unwrap();
+    ///     let mv = Vector2::new(
+    ///         v.x * mat[0][0] + v.y * mat[1][0],
+    ///         v.x * mat[0][1] + v.y * mat[1][1],
+    ///     );
+    ///     assert!(Complex::norm(mv.x - e * v.x) < EPS);
+    ///     assert!(Complex::norm(mv.y - e * v.y) < EPS);
+    /// });
+    ///
+    /// // Jordan block: the eigen space of the double eigen value is one dimensional.
+    /// let mat = Matrix2::new(2.0, 0.0, 1.0, 2.0);
+    /// let pairs = mat.eigenvectors();
+    /// assert_eq!(pairs.iter().filter(|(_, v)| v.is_some()).count(), 1);
+    /// ```
+    fn eigenvectors(self) -> Self::EigenVectors;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// spectral quantities derived from the [eigen values](EigenValues) $\lambda_i$, and the numerical abscissa
+///
+/// The eigen values are computed by [`EigenValues::eigenvalues_by_schur`]. If $M$ has an infinite or
+/// NaN entry, or the Schur decomposition fails, the spectral quantities are NaN and $M$ is not regarded
+/// as stable.
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::*;
+/// const EPS: f64 = 1.0e-10;
+///
+/// // the eigen values are -1 ± 2i
+/// let mat = Matrix2::new(-1.0, -2.0, 2.0, -1.0);
+/// assert!(f64::abs(mat.spectral_radius() - f64::sqrt(5.0)) < EPS);
+/// assert!(f64::abs(mat.spectral_abscissa() + 1.0) < EPS);
+/// assert!(mat.is_stable_continuous());
+/// assert!(!mat.is_stable_discrete());
+/// assert!((mat * 0.4).is_stable_discrete());
+///
+/// // stable, but transiently growing
+/// let mat = Matrix2::new(-1.0, 0.0, 10.0, -2.0);
+/// assert!(mat.is_stable_continuous());
+/// assert!(mat.numerical_abscissa() > 0.0);
+/// ```
+pub trait Spectral: EigenValues {
+    #[cfg_attr(doc, katexit::katexit)]
+    /// spectral radius: $\rho(M) = \max_i |\lambda_i|$, a lower bound of any operator norm.
+    fn spectral_radius(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// spectral abscissa: $\alpha(M) = \max_i \mathrm{Re} \lambda_i$, the asymptotic growth rate of $\exp(t M)$.
+    fn spectral_abscissa(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// numerical abscissa: $\omega(M) = \max \{ \mathrm{Re}\, x^T M x \mid \|x\|_2 = 1 \}$, the maximum real
+    /// part of the numerical range, which is the maximum eigen value of $(M + M^T) / 2$ and equals to
+    /// [`LogarithmicNorm::log_norm_l2`]. This is the initial growth rate of $\|\exp(t M)\|_2$, and
+    /// $\alpha(M) \le \omega(M)$.
+    fn numerical_abscissa(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns `true` if the continuous system $\dot{x} = M x$ is asymptotically stable, i.e. $\alpha(M) < 0$.
+    fn is_stable_continuous(self) -> bool {
+        self.spectral_abscissa() < Self::Scalar::zero()
+    }
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns `true` if the discrete system $x_{k + 1} = M x_k$ is asymptotically stable, i.e. $\rho(M) < 1$.
+    fn is_stable_discrete(self) -> bool {
+        self.spectral_radius() < Self::Scalar::one()
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// extension for eigen values and eigen vectors of symmetric matrices
+pub trait SymmetricEigen: SquareMatrix
+where
+    Self::Scalar: BaseFloat,
+{
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(\Lambda, V)$: the real eigen values in ascending order and the orthogonal matrix
+    /// whose columns are the corresponding unit eigen vectors, i.e. $M = V \mathrm{diag}(\Lambda) V^T$.
+    ///
+    /// Only the symmetric part $(M + M^T) / 2$ is referred. The eigen values and vectors are computed
+    /// by the cyclic Jacobi method, which is accurate even for nearly repeated eigen values.
+    /// If the matrix has an infinite or NaN entry, all the entries of the results are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix3::new(2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 5.0);
+    /// let (values, vectors) = mat.symmetric_eigen();
+    /// assert!((values - Vector3::new(1.0, 3.0, 5.0)).magnitude() < EPS);
+    /// assert!((vectors.transpose() * vectors - Matrix3::identity()).norm_l1() < EPS);
+    /// let diag = Matrix3::from_diagonal(values);
+    /// assert!((vectors * diag * vectors.transpose() - mat).norm_l1() < EPS);
+    /// ```
+    fn symmetric_eigen(self) -> (Self::ColumnRow, Self);
 }
 
 #[cfg_attr(doc, katexit::katexit)]
@@ -73,6 +272,194 @@ pub trait OperatorNorm: VectorSpace {
     fn norm_linf(self) -> Self::Scalar;
 }
 
+#[cfg_attr(doc, katexit::katexit)]
+/// [operator norms](OperatorNorm) for general $L^p$
+pub trait OperatorNormLp: OperatorNorm {
+    #[cfg_attr(doc, katexit::katexit)]
+    /// operator norm for $L^p$ with $1 \le p \le \infty$:
+    /// $\|M\|_p = \max_{\|x\|_p = 1} \|M x\|_p$.
+    ///
+    /// For $p = 1, \infty$, this is [`OperatorNorm::norm_l1`] and [`OperatorNorm::norm_linf`], and for
+    /// $p = 2$, the maximum singular value given by [`SingularValueDecomposition::svd`]. For the other $p$, the norm is
+    /// estimated by Boyd's power method in the form of N. J. Higham, started from several vectors
+    /// including the unit vectors. The estimate $e$ is always a lower bound, and in $n$ dimension
+    /// $$
+    /// e \le \|M\|_p \le n^{1 - 1/p} e,
+    /// $$
+    /// while $e$ agrees with $\|M\|_p$ up to rounding errors in practice.
+    /// Returns NaN for $p < 1$, which does not define a norm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix2::new(1.0, 3.0, -2.0, 4.0);
+    /// assert_eq!(mat.norm_lp(1.0), mat.norm_l1());
+    /// assert_eq!(mat.norm_lp(f64::INFINITY), mat.norm_linf());
+    /// assert!(f64::abs(mat.norm_lp(2.0) - mat.norm_l2()) < EPS);
+    ///
+    /// // diagonal matrices attain the maximum absolute value of the entries
+    /// let diag = Matrix3::from_diagonal(Vector3::new(1.0, -5.0, 2.0));
+    /// assert!(f64::abs(diag.norm_lp(4.0) - 5.0) < EPS);
+    ///
+    /// // the all-one matrix: n^{1 - 1/p} n^{1/p} = n
+    /// let ones = Matrix3::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+    /// assert!(f64::abs(ones.norm_lp(1.5) - 3.0) < EPS);
+    /// ```
+    fn norm_lp(self, p: Self::Scalar) -> Self::Scalar;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// [logarithmic norms](https://en.wikipedia.org/wiki/Logarithmic_norm), or matrix measures, for the
+/// [operator norms](OperatorNorm): $\mu(M) = \lim_{h \to +0} (\|I + h M\| - 1) / h$.
+///
+/// The logarithmic norm may be negative, and bounds the growth of the matrix exponential:
+/// $$
+/// \|\exp(t M)\| \le \exp(t \mu(M)) \quad (t \ge 0).
+/// $$
+/// A NaN entry of the matrix makes the logarithmic norms NaN.
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::*;
+/// const EPS: f64 = 1.0e-10;
+///
+/// let mat = Matrix2::new(-3.0, 1.0, 2.0, -4.0);
+/// assert_eq!(mat.log_norm_l1(), -2.0);
+/// assert_eq!(mat.log_norm_linf(), -1.0);
+/// // the maximum eigen value of ((-3, 1.5), (1.5, -4))
+/// let ans = (-7.0 + f64::sqrt(10.0)) / 2.0;
+/// assert!(f64::abs(mat.log_norm_l2() - ans) < EPS);
+///
+/// // the decay of the exponential is bounded.
+/// let t = 0.5;
+/// assert!((mat * t).exp().norm_l1() <= f64::exp(t * mat.log_norm_l1()));
+/// ```
+pub trait LogarithmicNorm: OperatorNorm {
+    #[cfg_attr(doc, katexit::katexit)]
+    /// logarithmic norm for [`OperatorNorm::norm_l1`]: $\mu_1(M) = \max_j \left(m_{jj} + \sum_{i \ne j} |m_{ij}|\right)$.
+    fn log_norm_l1(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// logarithmic norm for [`OperatorNorm::norm_l2`]: the maximum eigen value of the symmetric part
+    /// $(M + M^T) / 2$, given by [`SymmetricEigen::symmetric_eigen`].
+    fn log_norm_l2(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// logarithmic norm for [`OperatorNorm::norm_linf`]: $\mu_\infty(M) = \max_i \left(m_{ii} + \sum_{j \ne i} |m_{ij}|\right)$.
+    fn log_norm_linf(self) -> Self::Scalar;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// condition numbers $\kappa(M) = \|M\| \|M^{-1}\|$ for the [operator norms](OperatorNorm)
+///
+/// The condition numbers are at least one, infinity for singular matrices, and NaN for matrices with
+/// an infinite or NaN entry.
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::*;
+/// const EPS: f64 = 1.0e-10;
+///
+/// let mat = Matrix2::new(1.0, 0.0, 1.0e3, 1.0);
+/// assert!(f64::abs(mat.cond_l1() - 1002001.0) < EPS);
+/// assert!(f64::abs(mat.cond_linf() - 1002001.0) < EPS);
+/// assert!(mat.cond_l2() > 1.0e6);
+///
+/// let rot = Matrix2::from_angle(Rad(1.0));
+/// assert!(f64::abs(rot.cond_l2() - 1.0) < EPS);
+///
+/// let singular = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+/// assert_eq!(singular.cond_l1(), f64::INFINITY);
+/// assert_eq!(singular.cond_l2(), f64::INFINITY);
+/// ```
+pub trait ConditionNumber: OperatorNorm {
+    #[cfg_attr(doc, katexit::katexit)]
+    /// condition number for [`OperatorNorm::norm_l1`], computed with the inverse by the LU decomposition.
+    /// Returns infinity if [`LuDecomposition::lu`] fails with [`DecompositionError::RankDeficient`], i.e.
+    /// the matrix is singular, and NaN if the matrix has an infinite or NaN entry.
+    fn cond_l1(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// condition number for [`OperatorNorm::norm_l2`]: $\sigma_{\max} / \sigma_{\min}$, the ratio of
+    /// the maximum and the minimum singular values given by [`SingularValueDecomposition::svd`].
+    /// Returns infinity if $\sigma_{\min} \le n \varepsilon \sigma_{\max}$, where the matrix is numerically
+    /// singular and the computed $\sigma_{\min}$ is dominated by the rounding errors.
+    /// Returns NaN if the matrix has an infinite or NaN entry.
+    fn cond_l2(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// condition number for [`OperatorNorm::norm_linf`], computed with the inverse by the LU decomposition.
+    /// Returns infinity if [`LuDecomposition::lu`] fails with [`DecompositionError::RankDeficient`], i.e.
+    /// the matrix is singular, and NaN if the matrix has an infinite or NaN entry.
+    fn cond_linf(self) -> Self::Scalar;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// entrywise norms: the Frobenius norm and the maximum absolute value of the entries
+///
+/// They are not [operator norms](OperatorNorm), but cheap to compute and often used as error metrics.
+/// They are related to $\|M\|_2$ of [`OperatorNorm::norm_l2`] in $n$ dimension by
+/// $$
+/// \|M\|_2 \le \|M\|_F \le \sqrt{n} \|M\|_2, \quad \|M\|_{\max} \le \|M\|_2 \le n \|M\|_{\max}.
+/// $$
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::*;
+/// const EPS: f64 = 1.0e-10;
+///
+/// let mat = Matrix2::new(1.0, 3.0, -2.0, 4.0);
+/// assert!(f64::abs(mat.norm_frobenius() - f64::sqrt(30.0)) < EPS);
+/// assert_eq!(mat.norm_max(), 4.0);
+/// assert!(mat.norm_l2() <= mat.norm_frobenius());
+/// ```
+pub trait EntrywiseNorm: VectorSpace {
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Frobenius norm: $\|M\|_F = \sqrt{\sum_{i, j} m_{ij}^2}$, the square root of the sum of the squares of
+    /// the singular values.
+    fn norm_frobenius(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// max norm: $\|M\|_{\max} = \max_{i, j} |m_{ij}|$.
+    fn norm_max(self) -> Self::Scalar;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// [Schatten norms](https://en.wikipedia.org/wiki/Schatten_norm): the $l^p$ norms of the singular values
+///
+/// The singular values are given by [`SingularValueDecomposition::svd`]. The Schatten $p$-norm is the nuclear norm for
+/// $p = 1$, [`EntrywiseNorm::norm_frobenius`] for $p = 2$, and [`OperatorNorm::norm_l2`] for $p = \infty$.
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::*;
+/// const EPS: f64 = 1.0e-10;
+///
+/// // singular values: 3, 2 and 0
+/// let mat = Matrix3::from_diagonal(Vector3::new(2.0, -3.0, 0.0));
+/// assert!(f64::abs(mat.norm_nuclear() - 5.0) < EPS);
+/// assert!(f64::abs(mat.norm_schatten(2.0) - mat.norm_frobenius()) < EPS);
+/// assert!(f64::abs(mat.norm_schatten(3.0) - f64::cbrt(35.0)) < EPS);
+/// assert!(f64::abs(mat.norm_schatten(f64::INFINITY) - mat.norm_l2()) < EPS);
+/// ```
+pub trait SchattenNorm: VectorSpace {
+    #[cfg_attr(doc, katexit::katexit)]
+    /// nuclear norm, or trace norm: $\|M\|_* = \sum_i \sigma_i$, the Schatten $1$-norm.
+    fn norm_nuclear(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Schatten $p$-norm: $\|M\|_{S_p} = \left(\sum_i \sigma_i^p\right)^{1/p}$ for $1 \le p \le \infty$.
+    ///
+    /// For $0 < p < 1$, the same formula gives a quasi-norm, which does not satisfy the triangle inequality.
+    fn norm_schatten(self, p: Self::Scalar) -> Self::Scalar;
+}
+
 /// calculate exponential value
 pub trait Exponential: OperatorNorm + std::ops::AddAssign<Self> + One
 where
@@ -80,6 +467,10 @@ where
 {
     /// calculate exponential
     ///
+    /// The default implementation is the truncated Taylor series. The implementations for `Matrix2`,
+    /// `Matrix3` and `Matrix4` are the scaling and squaring method with the Padé approximants
+    /// of degree up to 13 by N. J. Higham, whose scaling is chosen by [`OperatorNorm::norm_l1`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -90,6 +481,11 @@ where
     /// let res = x.exp();
     /// let ans = Matrix2::from_angle(Rad(1.0));
     /// assert!((res - ans).norm_l1() < 1.0e-10);
+    ///
+    /// // matrix with large norm
+    /// let res = (x * 50.0).exp();
+    /// let ans = Matrix2::from_angle(Rad(50.0));
+    /// assert!((res - ans).norm_l1() < 1.0e-10);
     /// ```
     fn exp(self) -> Self {
         use num_traits::{Float, NumCast};
@@ -107,6 +503,221 @@ where
     }
 }
 
+#[cfg_attr(doc, katexit::katexit)]
+/// calculate logarithm value
+pub trait Logarithm: Exponential
+where
+    Self::Scalar: BaseFloat,
+{
+    #[cfg_attr(doc, katexit::katexit)]
+    /// calculate the principal logarithm, the inverse of [`Exponential::exp`].
+    ///
+    /// The logarithm is calculated by the inverse scaling and squaring method on the real Schur form
+    /// $M = Q T Q^T$: the square roots of $T$ are taken by the recurrence of Björck and Hammarling
+    /// until it is close to the identity, and then the Padé approximant of $\log(I + X)$ is applied.
+    ///
+    /// Returns `None` if the matrix has a non-positive real eigen value, including the eigen values
+    /// within the rounding errors from the negative real axis, since the real principal logarithm
+    /// does not exist. Returns `None` also if the matrix has a non-finite entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    ///
+    /// let x = Matrix2::new(0.5, 1.0, -1.0, 0.2);
+    /// let res = x.exp().log().unwrap();
+    /// assert!((res - x).norm_l1() < 1.0e-10);
+    ///
+    /// let mat = Matrix2::from_diagonal(Vector2::new(2.0, -1.0));
+    /// assert!(mat.log().is_none());
+    /// ```
+    fn log(self) -> Option<Self>;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// LU decomposition and linear systems
+pub trait LuDecomposition: SquareMatrix
+where
+    Self::Scalar: BaseFloat,
+{
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(P, L, U)$ of the LU decomposition with partial pivoting: $PM = LU$.
+    ///
+    /// - $P$: permutation matrix
+    /// - $L$: lower unitriangular matrix whose entries are not greater than one in absolute value
+    /// - $U$: upper triangular matrix
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecompositionError::RankDeficient`] if $M$ is singular or nearly singular, i.e. the absolute
+    /// value of a pivot is not greater than $n \varepsilon$ times the maximum absolute value of the entries of $M$.
+    /// Returns [`DecompositionError::NonFinite`] if $M$ has an infinite or NaN entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    /// let (p, l, u) = mat.lu().unwrap();
+    /// assert!(l[0][0] == 1.0 && l[1][0] == 0.0 && l[2][0] == 0.0);
+    /// assert!(u[0][1] == 0.0 && u[0][2] == 0.0 && u[1][2] == 0.0);
+    /// assert!((p * mat - l * u).norm_l1() < EPS);
+    ///
+    /// let singular = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert!(singular.lu().is_err());
+    /// ```
+    fn lu(self) -> Result<(Self, Self, Self), DecompositionError<Self::Scalar>>;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns the solution $x$ of $Mx = b$ by the LU decomposition.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`LuDecomposition::lu`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    /// let b = Vector3::new(1.0, -1.0, 2.0);
+    /// let x = mat.solve(b).unwrap();
+    /// assert!((mat * x - b).magnitude() < EPS);
+    /// ```
+    fn solve(self, b: Self::ColumnRow)
+        -> Result<Self::ColumnRow, DecompositionError<Self::Scalar>>;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns the solution $X$ of $MX = B$ by the LU decomposition.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`LuDecomposition::lu`].
+    fn solve_matrix(self, b: Self) -> Result<Self, DecompositionError<Self::Scalar>>;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Moore–Penrose pseudo inverse and least squares
+pub trait PseudoInverse: SquareMatrix
+where
+    Self::Scalar: BaseFloat,
+{
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(M^+, r)$: the Moore–Penrose pseudo inverse $M^+$ and the numerical rank $r$ of $M$.
+    ///
+    /// $M^+ = V \Sigma^+ U^T$ is given by [`SingularValueDecomposition::svd`] $M = U \Sigma V^T$, where the singular
+    /// values not greater than `tol` times the maximum one are regarded as zero and the others are inverted.
+    /// If $M$ is invertible and `tol` is small enough, $M^+ = M^{-1}$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+    /// let (p, rank) = mat.pseudo_inverse(1.0e-12);
+    /// assert_eq!(rank, 2);
+    /// // the Penrose conditions
+    /// assert!((mat * p * mat - mat).norm_l1() < EPS);
+    /// assert!((p * mat * p - p).norm_l1() < EPS);
+    /// assert!(((mat * p).transpose() - mat * p).norm_l1() < EPS);
+    /// assert!(((p * mat).transpose() - p * mat).norm_l1() < EPS);
+    /// ```
+    fn pseudo_inverse(self, tol: Self::Scalar) -> (Self, usize);
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(x, r)$: the minimum norm least squares solution $x = M^+ b$ of $Mx = b$ and
+    /// the numerical rank $r$ of $M$.
+    ///
+    /// $x$ minimizes $\|Mx - b\|$, and $\|x\|$ among the minimizers. The singular values not greater than
+    /// $n \varepsilon$ times the maximum one are regarded as zero, as in [`PseudoInverse::pseudo_inverse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// // degenerate constraints: x + y = 1 and 2x + 2y = 4
+    /// let mat = Matrix2::new(1.0, 2.0, 1.0, 2.0);
+    /// let (x, rank) = mat.least_squares(Vector2::new(1.0, 4.0));
+    /// assert_eq!(rank, 1);
+    /// assert!((x - Vector2::new(0.9, 0.9)).magnitude() < EPS);
+    /// ```
+    fn least_squares(self, b: Self::ColumnRow) -> (Self::ColumnRow, usize) {
+        exp_decomp::least_squares(self, b)
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// numerical rank, null space and column space
+///
+/// All the methods are based on [`SingularValueDecomposition::svd`] $M = U \Sigma V^T$: the singular values not greater than
+/// `tol` times the maximum one are regarded as zero, as in [`PseudoInverse::pseudo_inverse`].
+pub trait FundamentalSubspaces: SquareMatrix
+where
+    Self::Scalar: BaseFloat,
+{
+    /// Returns the numerical rank: the number of the singular values greater than `tol` times the maximum one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    ///
+    /// let mat = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    /// assert_eq!(mat.rank(1.0e-12), 2);
+    /// assert_eq!(Matrix3::<f64>::zero().rank(1.0e-12), 0);
+    /// ```
+    fn rank(self, tol: Self::Scalar) -> usize;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns an orthonormal basis of the null space $\{x \mid Mx = 0\}$: the columns of $V$ for
+    /// the vanishing singular values. Its length is $n - r$, where $r$ is [`FundamentalSubspaces::rank`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// // the rotation axis is the null space of R - I
+    /// let axis = Vector3::new(0.0, 0.6, 0.8);
+    /// let rot = Matrix3::from_axis_angle(axis, Rad(1.0));
+    /// let null = (rot - Matrix3::identity()).null_space(1.0e-12);
+    /// assert_eq!(null.len(), 1);
+    /// assert!(f64::abs(null[0].dot(axis)) > 1.0 - EPS);
+    /// ```
+    fn null_space(self, tol: Self::Scalar) -> Vec<Self::ColumnRow>;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns an orthonormal basis of the column space $\{Mx\}$, the range of $M$: the columns of $U$ for
+    /// the non-vanishing singular values. Its length is [`FundamentalSubspaces::rank`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix3::new(1.0, 2.0, 0.0, 2.0, 4.0, 0.0, 0.0, 1.0, 0.0);
+    /// let range = mat.column_space(1.0e-12);
+    /// assert_eq!(range.len(), 2);
+    /// // the range is the xy-plane
+    /// assert!(range.iter().all(|v| f64::abs(v.z) < EPS));
+    /// ```
+    fn column_space(self, tol: Self::Scalar) -> Vec<Self::ColumnRow>;
+}
+
 #[cfg_attr(doc, katexit::katexit)]
 /// some decompositions of matrix
 pub trait Decomposition: VectorSpace {
@@ -116,5 +727,509 @@ pub trait Decomposition: VectorSpace {
     /// - $K$: orthonormal matrix
     /// - $A$: diagonal matrix
     /// - $N$: upper-half unipotent matrix
-    fn iwasawa_decomposition(self) -> Option<(Self, Self, Self)>;
+    ///
+    /// The columns of $M$ are orthogonalized by the modified Gram–Schmidt process with one
+    /// reorthogonalization, so that $K$ is orthonormal up to rounding errors even if $M$ is ill-conditioned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecompositionError::NonFinite`] if $M$ has an infinite or NaN entry, and
+    /// [`DecompositionError::RankDeficient`] with the first dependent column if $M$ is exactly singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    /// let (k, a, n) = mat.iwasawa_decomposition().unwrap();
+    /// assert!((k.transpose() * k - Matrix3::identity()).norm_l1() < EPS);
+    /// assert!((k * a * n - mat).norm_l1() < EPS);
+    ///
+    /// let mat = Matrix3::new(1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 7.0, 8.0, 10.0);
+    /// match mat.iwasawa_decomposition() {
+    ///     Err(DecompositionError::RankDeficient { column, magnitude }) => {
+    ///         assert_eq!(column, 1);
+    ///         assert_eq!(magnitude, 0.0);
+    ///     }
+    ///     res => panic!("{res:?}"),
+    /// }
+    /// ```
+    fn iwasawa_decomposition(self) -> Result<(Self, Self, Self), DecompositionError<Self::Scalar>> {
+        self.iwasawa_decomposition_with_tolerance(Self::Scalar::zero())
+    }
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(K, A, N)$ of the Iwasawa decomposition: $M = KAN$, rejecting nearly singular matrices.
+    ///
+    /// The $k$-th column $a_k$ of $M$ is rejected if the norm of its component $v_k$ orthogonal to the
+    /// previous columns satisfies $\|v_k\| \le \mathrm{tol} \|a_k\|$. Then the diagonal entry of $A$ would
+    /// be tiny and the entries of $N$ would be huge. [`Decomposition::iwasawa_decomposition`] is the case
+    /// $\mathrm{tol} = 0$.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecompositionError::NonFinite`] if $M$ has an infinite or NaN entry, and
+    /// [`DecompositionError::RankDeficient`] with the index $k$ and $\|v_k\|$ of the rejected column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    ///
+    /// let mat = Matrix2::new(1.0, 0.0, 1.0, 1.0e-9);
+    /// assert!(mat.iwasawa_decomposition().is_ok());
+    /// match mat.iwasawa_decomposition_with_tolerance(1.0e-6) {
+    ///     Err(DecompositionError::RankDeficient { column, magnitude }) => {
+    ///         assert_eq!(column, 1);
+    ///         assert!(f64::abs(magnitude - 1.0e-9) < 1.0e-15);
+    ///     }
+    ///     res => panic!("{res:?}"),
+    /// }
+    /// ```
+    fn iwasawa_decomposition_with_tolerance(
+        self,
+        tol: Self::Scalar,
+    ) -> Result<(Self, Self, Self), DecompositionError<Self::Scalar>>;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// singular value decomposition and polar decomposition
+pub trait SingularValueDecomposition: VectorSpace {
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(U, \Sigma, V^T)$ of the singular value decomposition: $M = U \Sigma V^T$.
+    ///
+    /// - $U$, $V$: orthonormal matrices
+    /// - $\Sigma$: diagonal matrix of the singular values, non-negative and in descending order
+    ///
+    /// The decomposition is computed by the one-sided Jacobi method, which orthogonalizes the columns
+    /// of $M$ directly without forming $M^TM$, so that the condition number is not squared.
+    /// $M$ is scaled by the maximum absolute value of the entries beforehand, so that the inner products
+    /// of the columns neither overflow nor underflow even for huge or tiny entries.
+    /// If $M$ has an infinite or NaN entry, all the entries of the results are NaN, and so are
+    /// the results of the other methods based on the singular values, while the numerical rank is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// // rank-deficient matrix
+    /// let mat = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+    /// let (u, sigma, vt) = mat.svd();
+    /// assert!((u.transpose() * u - Matrix3::identity()).norm_l1() < EPS);
+    /// assert!((vt * vt.transpose() - Matrix3::identity()).norm_l1() < EPS);
+    /// assert!(sigma[0][0] >= sigma[1][1] && sigma[1][1] >= sigma[2][2]);
+    /// assert!(f64::abs(sigma[2][2]) < EPS);
+    /// assert!((u * sigma * vt - mat).norm_l1() < EPS);
+    /// ```
+    fn svd(self) -> (Self, Self, Self);
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(R, S)$ of the polar decomposition: $M = RS$.
+    ///
+    /// - $R$: orthonormal matrix, the nearest one to $M$
+    /// - $S$: symmetric positive semi-definite matrix
+    ///
+    /// If `proper` is `true`, $R$ is forced to be a rotation, i.e. $\det R = 1$.
+    /// Then, if $\det M < 0$, $S$ has a negative eigen value whose absolute value is
+    /// the minimum singular value of $M$.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecompositionError::RankDeficient`] if $M$ is singular, i.e. the minimum singular value
+    /// is not greater than $n \varepsilon$ times the maximum one.
+    /// Returns [`DecompositionError::NonFinite`] if $M$ has an infinite or NaN entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// // re-orthonormalize a drifting rotation
+    /// let rot = Matrix3::from_angle_z(Rad(0.5)) * Matrix3::from_angle_x(Rad(1.0));
+    /// let drift = Matrix3::new(1.0e-3, 0.0, 2.0e-3, 0.0, -1.0e-3, 0.0, 0.0, 3.0e-3, 1.0e-3);
+    /// let (r, s) = (rot + drift).polar_decomposition(true).unwrap();
+    /// assert!((r.transpose() * r - Matrix3::identity()).norm_l1() < EPS);
+    /// assert!(f64::abs(r.determinant() - 1.0) < EPS);
+    /// assert!((r - rot).norm_l1() < 1.0e-2);
+    /// assert!((s - s.transpose()).norm_l1() < EPS);
+    /// assert!((r * s - rot - drift).norm_l1() < EPS);
+    ///
+    /// // singular matrix
+    /// let mat = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+    /// assert!(mat.polar_decomposition(false).is_err());
+    /// ```
+    fn polar_decomposition(
+        self,
+        proper: bool,
+    ) -> Result<(Self, Self), DecompositionError<Self::Scalar>>;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// QR decompositions by the Householder reflections
+pub trait QrDecomposition: VectorSpace {
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(Q, R)$ of the QR decomposition: $M = QR$.
+    ///
+    /// - $Q$: orthonormal matrix
+    /// - $R$: upper triangular matrix whose diagonal is non-negative
+    ///
+    /// The decomposition is computed by the Householder reflections, and always succeeds
+    /// even if $M$ is singular. If $M$ has an infinite or NaN entry, all the entries of $Q$ and $R$ are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+    /// let (q, r) = mat.qr();
+    /// assert!((q.transpose() * q - Matrix3::identity()).norm_l1() < EPS);
+    /// assert!(r[0][1] == 0.0 && r[0][2] == 0.0 && r[1][2] == 0.0);
+    /// assert!((q * r - mat).norm_l1() < EPS);
+    /// ```
+    fn qr(self) -> (Self, Self);
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(R, Q)$ of the RQ decomposition: $M = RQ$.
+    ///
+    /// - $R$: upper triangular matrix whose diagonal is non-negative
+    /// - $Q$: orthonormal matrix
+    ///
+    /// This is the mirror of [`QrDecomposition::qr`]: the rows of $M$ are orthogonalized from the bottom.
+    /// It is computed by the QR decomposition of $(JM)^T$, where $J$ is the reversal permutation, and
+    /// always succeeds even if $M$ is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+    /// let (r, q) = mat.rq();
+    /// assert!((q.transpose() * q - Matrix3::identity()).norm_l1() < EPS);
+    /// assert!(r[0][1] == 0.0 && r[0][2] == 0.0 && r[1][2] == 0.0);
+    /// assert!(r[0][0] >= 0.0 && r[1][1] >= 0.0 && r[2][2] >= 0.0);
+    /// assert!((r * q - mat).norm_l1() < EPS);
+    /// ```
+    fn rq(self) -> (Self, Self);
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(Q, R, P, r)$ of the QR decomposition with column pivoting: $MP = QR$.
+    ///
+    /// - $Q$: orthonormal matrix
+    /// - $R$: upper triangular matrix whose diagonal is non-negative and non-increasing
+    /// - $P$: permutation matrix
+    /// - $r$: the numerical rank, the number of the diagonal entries of $R$ greater than
+    ///   $n \varepsilon R_{00}$
+    ///
+    /// If $M$ has an infinite or NaN entry, all the entries of $Q$ and $R$ are NaN, $P$ is the identity,
+    /// and the numerical rank is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+    /// let (q, r, p, rank) = mat.qr_pivoted();
+    /// assert_eq!(rank, 2);
+    /// assert!((q.transpose() * q - Matrix3::identity()).norm_l1() < EPS);
+    /// assert!(r[0][0] >= r[1][1] && r[1][1] >= r[2][2]);
+    /// assert!((q * r - mat * p).norm_l1() < EPS);
+    /// ```
+    fn qr_pivoted(self) -> (Self, Self, Self, usize);
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// decompositions of symmetric matrices by the Cholesky method
+pub trait CholeskyDecomposition: VectorSpace {
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $L$ of the Cholesky decomposition $M = LL^T$ of the symmetric positive definite matrix $M$.
+    ///
+    /// - $L$: lower triangular matrix whose diagonal is positive
+    ///
+    /// Only the lower triangle of $M$ is referred.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecompositionError::NotPositiveDefinite`] with the first non-positive pivot
+    /// if $M$ is not positive definite.
+    /// Returns [`DecompositionError::NonFinite`] if $M$ has an infinite or NaN entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// // covariance matrix
+    /// let cov = Matrix3::new(4.0, 2.0, 0.4, 2.0, 2.0, 0.6, 0.4, 0.6, 1.0);
+    /// let l = cov.cholesky().unwrap();
+    /// assert!(l[1][0] == 0.0 && l[2][0] == 0.0 && l[2][1] == 0.0);
+    /// assert!((l * l.transpose() - cov).norm_l1() < EPS);
+    /// // correlated noise is given by `l * z` with the standard normal vector `z`.
+    ///
+    /// let mat = Matrix2::new(1.0, 2.0, 2.0, 1.0);
+    /// match mat.cholesky() {
+    ///     Err(DecompositionError::NotPositiveDefinite { pivot, value }) => {
+    ///         assert_eq!(pivot, 1);
+    ///         assert_eq!(value, -3.0);
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    fn cholesky(self) -> Result<Self, DecompositionError<Self::Scalar>>;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(L, D)$ of the $LDL^T$ decomposition $M = LDL^T$ of the symmetric positive
+    /// semi-definite matrix $M$.
+    ///
+    /// - $L$: lower unitriangular matrix
+    /// - $D$: diagonal matrix whose diagonal is non-negative
+    ///
+    /// Only the lower triangle of $M$ is referred. Unlike [`CholeskyDecomposition::cholesky`], no square root is
+    /// taken, and singular matrices are allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecompositionError::NotPositiveDefinite`] with the failed pivot if $M$ is not
+    /// positive semi-definite, i.e. a pivot is negative, or a pivot vanishes but the rest of the column does not.
+    /// The $j$-th pivot is regarded as vanishing if its absolute value is at most $\sqrt{\varepsilon} |M_{jj}|$,
+    /// and so is the rest of the column if each entry is at most $\sqrt{\varepsilon} \sqrt{|M_{ii} M_{jj}|}$.
+    /// Returns [`DecompositionError::NonFinite`] if $M$ has an infinite or NaN entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// // Gram matrix of linearly dependent vectors
+    /// let vecs = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 5.0, 7.0, 9.0);
+    /// let gram = vecs.transpose() * vecs;
+    /// let (l, d) = gram.ldlt().unwrap();
+    /// assert!(l[1][0] == 0.0 && l[2][0] == 0.0 && l[2][1] == 0.0);
+    /// assert!(f64::abs(d[2][2]) < EPS);
+    /// assert!((l * d * l.transpose() - gram).norm_l1() < EPS);
+    /// ```
+    fn ldlt(self) -> Result<(Self, Self), DecompositionError<Self::Scalar>>;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// real Schur decomposition and the reductions to the Hessenberg and the tridiagonal forms
+pub trait SchurDecomposition: VectorSpace {
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(Q, T)$ of the real Schur decomposition $M = Q T Q^T$.
+    ///
+    /// - $Q$: orthogonal matrix
+    /// - $T$: quasi upper triangular matrix
+    ///
+    /// The diagonal blocks of $T$ are $1 \times 1$ for real eigen values and $2 \times 2$ for pairs of
+    /// complex conjugate eigen values. Each $2 \times 2$ block $\begin{pmatrix} a & b \\ c & a \end{pmatrix}$
+    /// is standardized so that $bc < 0$, and its eigen values are $a \pm \sqrt{-bc}\, i$.
+    ///
+    /// The matrix is reduced to the Hessenberg form by the Householder reflections, and then
+    /// the Francis double shift QR steps are iterated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecompositionError::NotConverged`] if the QR steps do not converge.
+    /// Returns [`DecompositionError::NonFinite`] if $M$ has an infinite or NaN entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix3::new(1.0, 2.0, 3.0, -4.0, 5.0, 6.0, 7.0, 8.0, -9.0);
+    /// let (q, t) = mat.schur().unwrap();
+    /// assert!((q.transpose() * q - Matrix3::identity()).norm_l1() < EPS);
+    /// assert!((q * t * q.transpose() - mat).norm_l1() < EPS);
+    /// // t is quasi upper triangular
+    /// assert_eq!(t[0][2], 0.0);
+    /// assert!(t[0][1] == 0.0 || t[1][2] == 0.0);
+    /// ```
+    fn schur(self) -> Result<(Self, Self), DecompositionError<Self::Scalar>>;
+
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(Q, H)$ such that $M = Q H Q^T$ by the Householder reflections.
+    ///
+    /// - $Q$: orthogonal matrix
+    /// - $H$: upper Hessenberg matrix, i.e. the entries below the first sub-diagonal are zero
+    ///
+    /// This is the first step of [`SchurDecomposition::schur`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix4::new(
+    ///     1.0, 2.0, 3.0, 4.0, -5.0, 6.0, 7.0, 8.0, 9.0, -1.0, 2.0, 3.0, 4.0, 5.0, -6.0, 7.0,
+    /// );
+    /// let (q, h) = mat.hessenberg();
+    /// assert!((q.transpose() * q - Matrix4::identity()).norm_l1() < EPS);
+    /// assert!((q * h * q.transpose() - mat).norm_l1() < EPS);
+    /// assert!(h[0][2] == 0.0 && h[0][3] == 0.0 && h[1][3] == 0.0);
+    /// ```
+    fn hessenberg(self) -> (Self, Self);
+
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns $(Q, T)$ such that $M = Q T Q^T$ for a symmetric matrix $M$ by the Householder reflections.
+    ///
+    /// - $Q$: orthogonal matrix
+    /// - $T$: symmetric tridiagonal matrix
+    ///
+    /// Only the symmetric part $(M + M^T) / 2$ is referred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let mat = Matrix4::new(
+    ///     4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+    /// );
+    /// let (q, t) = mat.tridiagonal();
+    /// assert!((q.transpose() * q - Matrix4::identity()).norm_l1() < EPS);
+    /// assert!((q * t * q.transpose() - mat).norm_l1() < EPS);
+    /// assert_eq!(t, t.transpose());
+    /// assert!(t[0][2] == 0.0 && t[0][3] == 0.0 && t[1][3] == 0.0);
+    /// ```
+    fn tridiagonal(self) -> (Self, Self);
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// decomposition of affine transforms into translation, rotation, scale and shear
+pub trait AffineDecomposition: Decomposition
+where
+    Self::Scalar: BaseFloat,
+{
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns the components of the affine transform $M = T R S H$, which are recomposed by
+    /// [`AffineComponents::recompose`].
+    ///
+    /// The linear part is decomposed by [`Decomposition::iwasawa_decomposition`] $K A N$:
+    /// $K$ is the rotation, $A$ is the scale and $N$ is the shear.
+    /// If the transform is a reflection, i.e. $\det K < 0$, the last column of $K$ and
+    /// the last component of the scale are negated so that $R$ is a proper rotation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecompositionError::NonFinite`] if $M$ has an infinite or NaN entry,
+    /// [`DecompositionError::NotAffine`] if the bottom row is not $(0, 0, 0, 1)$, and
+    /// [`DecompositionError::RankDeficient`] if the linear part is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cgmath::*;
+    /// use matext4cgmath::*;
+    /// const EPS: f64 = 1.0e-10;
+    ///
+    /// let rotation = Quaternion::from_axis_angle(Vector3::new(0.6, 0.8, 0.0), Rad(1.0));
+    /// let mat = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+    ///     * Matrix4::from(rotation)
+    ///     * Matrix4::from_nonuniform_scale(2.0, 3.0, -4.0);
+    /// let affine = mat.decompose_affine().unwrap();
+    /// assert!((affine.translation - Vector3::new(1.0, 2.0, 3.0)).magnitude() < EPS);
+    /// assert!(rotation.dot(affine.rotation).abs() > 1.0 - EPS);
+    /// assert!((affine.scale - Vector3::new(2.0, 3.0, -4.0)).magnitude() < EPS);
+    /// assert!(affine.shear.magnitude() < EPS);
+    /// assert!((affine.recompose() - mat).norm_l1() < EPS);
+    ///
+    /// // projective transform
+    /// let mat = perspective(Deg(60.0), 1.0, 0.1, 100.0);
+    /// assert_eq!(mat.decompose_affine(), Err(DecompositionError::NotAffine));
+    /// ```
+    fn decompose_affine(
+        self,
+    ) -> Result<AffineComponents<Self::Scalar>, DecompositionError<Self::Scalar>>;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// invariants of second order tensors in three dimensions, such as stress and strain tensors
+///
+/// For a tensor $M$, the deviatoric part $S = M - \frac{I_1}{3} I$ has the invariants
+/// $$
+/// J_2 = \frac{1}{2} \mathrm{tr}(S^2), \quad J_3 = \det S.
+/// $$
+/// The quantities defined by $J_2$ and $J_3$ are computed from the symmetric part $(M + M^T) / 2$.
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::*;
+/// use std::f64::consts::PI;
+/// const EPS: f64 = 1.0e-10;
+///
+/// // uniaxial tension
+/// let stress = Matrix3::from_diagonal(Vector3::new(0.0, 0.0, 3.0));
+/// assert_eq!(stress.principal_invariants(), [3.0, 0.0, 0.0]);
+/// assert_eq!(stress.volumetric(), Matrix3::from_value(1.0));
+/// assert!(f64::abs(stress.von_mises() - 3.0) < EPS);
+/// assert!(f64::abs(stress.lode_angle()) < EPS);
+/// assert!((stress.principal_values() - Vector3::new(3.0, 0.0, 0.0)).magnitude() < EPS);
+///
+/// // pure shear
+/// let stress = Matrix3::new(0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+/// assert!(f64::abs(stress.von_mises() - f64::sqrt(3.0)) < EPS);
+/// assert!(f64::abs(stress.lode_angle() - PI / 6.0) < EPS);
+/// assert!((stress.principal_values() - Vector3::new(1.0, 0.0, -1.0)).magnitude() < EPS);
+/// ```
+pub trait TensorInvariants: CharacteristicPolynomial
+where
+    Self::Scalar: BaseFloat,
+{
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns the principal invariants $[I_1, I_2, I_3]$: the trace, the sum of the principal
+    /// $2 \times 2$ minors and the determinant, i.e. the [characteristic polynomial](CharacteristicPolynomial)
+    /// is $x^3 - I_1 x^2 + I_2 x - I_3$.
+    fn principal_invariants(self) -> [Self::Scalar; 3];
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns the deviatoric part $M - \frac{I_1}{3} I$, whose trace is zero.
+    fn deviatoric(self) -> Self;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns the volumetric, or hydrostatic, part $\frac{I_1}{3} I$.
+    fn volumetric(self) -> Self;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns the von Mises equivalent stress $\sqrt{3 J_2}$.
+    fn von_mises(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns the Lode angle $\theta \in [0, \pi / 3]$ defined by
+    /// $$
+    /// \cos 3 \theta = \frac{3 \sqrt{3}}{2} \frac{J_3}{J_2^{3/2}}.
+    /// $$
+    /// $\theta = 0$ for the uniaxial tension, $\pi / 6$ for the pure shear, and $\pi / 3$ for the uniaxial
+    /// compression. Returns NaN if $J_2 = 0$, i.e. the tensor is purely volumetric, or if the tensor has
+    /// an infinite or NaN entry.
+    fn lode_angle(self) -> Self::Scalar;
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Returns the principal values, or principal stresses, of the symmetric part in descending order by
+    /// the trigonometric solution of the cubic equation:
+    /// $$
+    /// \sigma_k = \frac{I_1}{3} + 2 \sqrt{\frac{J_2}{3}} \cos\left(\theta - \frac{2 \pi k}{3}\right)
+    /// \quad (k = 0, 1, -1),
+    /// $$
+    /// where $\theta$ is the [Lode angle](TensorInvariants::lode_angle).
+    fn principal_values(self) -> Self::ColumnRow;
 }
@@ -0,0 +1,181 @@
+use crate::*;
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Returns the skew-symmetric matrix $\hat\omega$ such that $\hat\omega v = \omega \times v$.
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::lie;
+///
+/// let omega = Vector3::new(1.0, 2.0, 3.0);
+/// let v = Vector3::new(-2.0, 4.0, 1.0);
+/// assert_eq!(lie::hat(omega) * v, omega.cross(v));
+/// assert_eq!(lie::vee(lie::hat(omega)), omega);
+/// ```
+pub fn hat<F: BaseFloat>(omega: Vector3<F>) -> Matrix3<F> {
+    let zero = F::zero();
+    #[rustfmt::skip]
+    let res = Matrix3::new(
+        zero, omega.z, -omega.y,
+        -omega.z, zero, omega.x,
+        omega.y, -omega.x, zero,
+    );
+    res
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Returns the vector $\omega$ of the skew-symmetric part $\hat\omega = (M - M^T) / 2$, the inverse of [`hat`].
+pub fn vee<F: BaseFloat>(mat: Matrix3<F>) -> Vector3<F> {
+    let two = F::one() + F::one();
+    Vector3::new(
+        (mat[1][2] - mat[2][1]) / two,
+        (mat[2][0] - mat[0][2]) / two,
+        (mat[0][1] - mat[1][0]) / two,
+    )
+}
+
+/// Returns `(sin(t) / t, (1 - cos(t)) / t^2)` without cancellation.
+fn rodrigues_coefficients<F: BaseFloat>(theta: F) -> (F, F) {
+    let two = F::one() + F::one();
+    match theta == F::zero() {
+        true => (F::one(), F::one() / two),
+        false => {
+            let sinc_2 = F::sin(theta / two) / theta;
+            (F::sin(theta) / theta, two * sinc_2 * sinc_2)
+        }
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Returns the rotation $\exp(\hat\omega)$ by the Rodrigues formula:
+/// $$
+/// \exp(\hat\omega) = I + \frac{\sin\theta}{\theta} \hat\omega + \frac{1 - \cos\theta}{\theta^2} \hat\omega^2,
+/// \quad \theta = |\omega|.
+/// $$
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::*;
+///
+/// let omega = Vector3::new(0.3, -1.2, 0.5);
+/// let res = lie::exp_so3(omega);
+/// let ans = Matrix3::from_axis_angle(omega.normalize(), Rad(omega.magnitude()));
+/// assert!((res - ans).norm_l1() < 1.0e-12);
+/// assert!((lie::log_so3(res) - omega).magnitude() < 1.0e-12);
+/// ```
+pub fn exp_so3<F: BaseFloat>(omega: Vector3<F>) -> Matrix3<F> {
+    let (a, b) = rodrigues_coefficients(omega.magnitude());
+    let k = hat(omega);
+    Matrix3::identity() + k * a + k * k * b
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Returns the rotation vector $\omega$ with $|\omega| \leq \pi$ such that $\exp(\hat\omega) = R$,
+/// the inverse of [`exp_so3`].
+///
+/// For the rotation with the angle $\pi$, either of the two opposite vectors is returned.
+/// If $R$ has an infinite or NaN entry, all the entries of the result are NaN.
+pub fn log_so3<F: BaseFloat>(rot: Matrix3<F>) -> Vector3<F> {
+    if dense::check_finite(&rot.into()).is_err() {
+        return Vector3::from_value(F::nan());
+    }
+    let two = F::one() + F::one();
+    let u = vee(rot);
+    let cos = (rot.trace() - F::one()) / two;
+    let sin = u.magnitude();
+    let theta = F::atan2(sin, cos);
+    if cos >= F::zero() {
+        return match sin == F::zero() {
+            true => u,
+            false => u * (theta / sin),
+        };
+    }
+    // Near the angle pi, the axis n is taken from the symmetric part: (R + R^T) / 2 - cos I = (1 - cos) n n^T.
+    let sym = (rot + rot.transpose()) / two - Matrix3::from_value(cos);
+    let k = (0..3)
+        .max_by(|i, j| dense::total_cmp(&sym[*i][*i], &sym[*j][*j]))
+        .unwrap();
+    let n = sym[k].normalize();
+    match n.dot(u) < F::zero() {
+        true => -n * theta,
+        false => n * theta,
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Returns the rigid motion $\exp(\hat\xi)$ of the twist $\xi = (\omega, v)$:
+/// $$
+/// \exp(\hat\xi) = \begin{pmatrix} \exp(\hat\omega) & Vv \\\\ 0 & 1 \end{pmatrix}, \quad
+/// V = I + \frac{1 - \cos\theta}{\theta^2} \hat\omega + \frac{\theta - \sin\theta}{\theta^3} \hat\omega^2.
+/// $$
+///
+/// # Examples
+///
+/// ```
+/// use cgmath::*;
+/// use matext4cgmath::*;
+///
+/// let (omega, v) = (Vector3::new(0.3, -1.2, 0.5), Vector3::new(1.0, 2.0, -3.0));
+/// let res = lie::exp_se3(omega, v);
+/// let twist = Matrix4::from_cols(
+///     lie::hat(omega).x.extend(0.0),
+///     lie::hat(omega).y.extend(0.0),
+///     lie::hat(omega).z.extend(0.0),
+///     v.extend(0.0),
+/// );
+/// assert!((res - twist.exp()).norm_l1() < 1.0e-12);
+/// let (omega0, v0) = lie::log_se3(res);
+/// assert!((omega0 - omega).magnitude() < 1.0e-12);
+/// assert!((v0 - v).magnitude() < 1.0e-12);
+/// ```
+pub fn exp_se3<F: BaseFloat>(omega: Vector3<F>, v: Vector3<F>) -> Matrix4<F> {
+    let theta = omega.magnitude();
+    let (a, b) = rodrigues_coefficients(theta);
+    let c = se3_coefficient(theta);
+    let k = hat(omega);
+    let k2 = k * k;
+    let rot = Matrix3::identity() + k * a + k2 * b;
+    let trans = (Matrix3::identity() + k * b + k2 * c) * v;
+    let mut res = Matrix4::from(rot);
+    res[3] = trans.extend(F::one());
+    res
+}
+
+/// Returns `(t - sin(t)) / t^3`.
+fn se3_coefficient<F: BaseFloat>(theta: F) -> F {
+    let t2 = theta * theta;
+    match theta < F::from(0.1).unwrap() {
+        true => {
+            let c = |x: f64| F::from(x).unwrap();
+            c(1.0 / 6.0) - t2 / c(120.0) + t2 * t2 / c(5040.0) - t2 * t2 * t2 / c(362880.0)
+        }
+        false => (theta - F::sin(theta)) / (t2 * theta),
+    }
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Returns the twist $(\omega, v)$ such that [`exp_se3`]$(\omega, v) = M$ for the rigid motion $M$
+/// with $|\omega| \leq \pi$. If the rotation part has an infinite or NaN entry, all the entries of
+/// the result are NaN.
+pub fn log_se3<F: BaseFloat>(mat: Matrix4<F>) -> (Vector3<F>, Vector3<F>) {
+    let rot = Matrix3::from_cols(mat[0].truncate(), mat[1].truncate(), mat[2].truncate());
+    let omega = log_so3(rot);
+    let theta = omega.magnitude();
+    let t2 = theta * theta;
+    let two = F::one() + F::one();
+    // d = (1 - (t / 2) cot(t / 2)) / t^2
+    let d = match theta < F::from(0.1).unwrap() {
+        true => {
+            let c = |x: f64| F::from(x).unwrap();
+            c(1.0 / 12.0) + t2 / c(720.0) + t2 * t2 / c(30240.0) + t2 * t2 * t2 / c(1209600.0)
+        }
+        false => (F::one() - theta / two / F::tan(theta / two)) / t2,
+    };
+    let k = hat(omega);
+    let v_inv = Matrix3::identity() - k / two + k * k * d;
+    (omega, v_inv * mat[3].truncate())
+}
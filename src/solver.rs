@@ -178,11 +178,11 @@ pub fn pre_solve_quartic<F: BaseFloat>(p: F, q: F, r: F) -> [Complex<F>; 4] {
             let f = x
                 .iter()
                 .map(|t| (t * t * t * t + t * t * p + t * q + r).norm_sqr())
-                .max_by(|x, y| x.partial_cmp(y).unwrap())
+                .max_by(dense::total_cmp)
                 .unwrap();
             (x, f)
         })
-        .min_by(|x, y| x.1.partial_cmp(&y.1).unwrap())
+        .min_by(|x, y| dense::total_cmp(&x.1, &y.1))
         .unwrap()
         .0;
     // refinement by Newton method
@@ -0,0 +1,61 @@
+use crate::*;
+
+/// Returns `(J_2, J_3)` of the symmetric part of `m`.
+fn deviatoric_invariants<F: BaseFloat>(m: Matrix3<F>) -> (F, F) {
+    let two = F::one() + F::one();
+    let s = ((m + m.transpose()) / two).deviatoric();
+    let sq = (0..3).fold(F::zero(), |sum, j| sum + s[j].magnitude2());
+    (sq / two, s.determinant())
+}
+
+/// Returns the Lode angle from `(J_2, J_3)`, clamping the rounding errors of the cosine but not NaN.
+fn lode_angle<F: BaseFloat>(j2: F, j3: F) -> F {
+    if j2 == F::zero() {
+        return F::nan();
+    }
+    let (two, three) = (F::from(2).unwrap(), F::from(3).unwrap());
+    let cos = three * F::sqrt(three) / two * j3 / F::powf(j2, three / two);
+    if cos.is_nan() {
+        return F::nan();
+    }
+    F::acos(F::min(F::max(cos, -F::one()), F::one())) / three
+}
+
+impl<F: BaseFloat> TensorInvariants for Matrix3<F> {
+    fn principal_invariants(self) -> [F; 3] {
+        let [a, b, c] = self.characteristic_polynomial();
+        [-a, b, -c]
+    }
+    fn deviatoric(self) -> Self {
+        self - self.volumetric()
+    }
+    fn volumetric(self) -> Self {
+        Matrix3::from_value(self.trace() / F::from(3).unwrap())
+    }
+    fn von_mises(self) -> F {
+        let (j2, _) = deviatoric_invariants(self);
+        F::sqrt(F::from(3).unwrap() * j2)
+    }
+    fn lode_angle(self) -> F {
+        let (j2, j3) = deviatoric_invariants(self);
+        lode_angle(j2, j3)
+    }
+    fn principal_values(self) -> Vector3<F> {
+        let three = F::from(3).unwrap();
+        let mean = self.trace() / three;
+        let (j2, j3) = deviatoric_invariants(self);
+        if j2 == F::zero() {
+            return Vector3::from_value(mean);
+        }
+        let theta = lode_angle(j2, j3);
+        let (r, phase) = (
+            F::from(2).unwrap() * F::sqrt(j2 / three),
+            Rad::<F>::turn_div_3().0,
+        );
+        Vector3::new(
+            mean + r * F::cos(theta),
+            mean + r * F::cos(theta - phase),
+            mean + r * F::cos(theta + phase),
+        )
+    }
+}
@@ -0,0 +1,30 @@
+//! random fixtures shared by the integration tests
+#![allow(dead_code)]
+
+use cgmath::*;
+
+/// Returns a vector whose entries are uniformly distributed in `[-5, 5]`.
+pub fn random_vector3() -> Vector3<f64> {
+    Vector3::new(
+        10.0 * rand::random::<f64>() - 5.0,
+        10.0 * rand::random::<f64>() - 5.0,
+        10.0 * rand::random::<f64>() - 5.0,
+    )
+}
+
+/// Returns a vector whose entries are uniformly distributed in `[-5, 5]`.
+pub fn random_vector4() -> Vector4<f64> {
+    Vector4::new(
+        10.0 * rand::random::<f64>() - 5.0,
+        10.0 * rand::random::<f64>() - 5.0,
+        10.0 * rand::random::<f64>() - 5.0,
+        10.0 * rand::random::<f64>() - 5.0,
+    )
+}
+
+/// Returns a matrix whose entries are uniformly distributed in `[-5, 5]`.
+pub fn random_matrix4() -> Matrix4<f64> {
+    let mut mat = Matrix4::zero();
+    (0..4).for_each(|i| (0..4).for_each(|j| mat[i][j] = 10.0 * rand::random::<f64>() - 5.0));
+    mat
+}
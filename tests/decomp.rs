@@ -1,5 +1,9 @@
 use cgmath::*;
 use matext4cgmath::*;
+use num_complex::Complex;
+
+mod common;
+use common::*;
 
 #[test]
 fn matrix2() {
@@ -39,7 +43,12 @@ fn matrix3() {
         );
         if mat.is_invertible() {
             let (k, a, n) = mat.iwasawa_decomposition().unwrap();
+            let k_err = k * k.transpose() - Matrix3::identity();
             let res = mat - k * a * n;
+            assert!(
+                k_err.norm_l1() < 1.0e-8,
+                "{_i} {k:?}\n{a:?}\n{n:?}\n{res:?}"
+            );
             assert!(res.norm_l1() < 1.0e-8, "{_i} {k:?}\n{a:?}\n{n:?}\n{res:?}");
         }
     });
@@ -68,8 +77,755 @@ fn matrix4() {
         );
         if mat.is_invertible() {
             let (k, a, n) = mat.iwasawa_decomposition().unwrap();
+            let k_err = k * k.transpose() - Matrix4::identity();
             let res = mat - k * a * n;
+            assert!(
+                k_err.norm_l1() < 1.0e-8,
+                "{_i} {k:?}\n{a:?}\n{n:?}\n{res:?}"
+            );
             assert!(res.norm_l1() < 1.0e-8, "{_i} {k:?}\n{a:?}\n{n:?}\n{res:?}");
         }
     });
 }
+
+#[test]
+fn iwasawa_errors() {
+    // exactly dependent columns
+    let mat = Matrix3::new(0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 3.0, 0.0);
+    match mat.iwasawa_decomposition() {
+        Err(DecompositionError::RankDeficient { column, magnitude }) => {
+            assert_eq!(column, 2);
+            assert_eq!(magnitude, 0.0);
+        }
+        res => panic!("{res:?}"),
+    }
+    let mut mat = Matrix4::<f64>::identity();
+    mat[0][0] = 0.0;
+    assert!(matches!(
+        mat.iwasawa_decomposition(),
+        Err(DecompositionError::RankDeficient { column: 0, .. })
+    ));
+
+    // non-finite entries
+    [f64::NAN, f64::INFINITY, f64::NEG_INFINITY]
+        .into_iter()
+        .for_each(|x| {
+            let mut mat = Matrix4::<f64>::identity();
+            mat[2][1] = x;
+            assert_eq!(
+                mat.iwasawa_decomposition(),
+                Err(DecompositionError::NonFinite)
+            );
+            assert_eq!(
+                mat.polar_decomposition(true),
+                Err(DecompositionError::NonFinite)
+            );
+            assert_eq!(mat.lu(), Err(DecompositionError::NonFinite));
+            assert_eq!(mat.cholesky(), Err(DecompositionError::NonFinite));
+            assert_eq!(mat.ldlt(), Err(DecompositionError::NonFinite));
+        });
+
+    // nearly dependent columns
+    (0..10000).for_each(|_i| {
+        let mut mat = random_matrix4();
+        let eps = 1.0e-6 * (0.5 + rand::random::<f64>());
+        mat[3] = mat[0] * 2.0 - mat[1] + random_vector4() * eps;
+        let (k, a, n) = mat.iwasawa_decomposition_with_tolerance(1.0e-14).unwrap();
+        assert!((k * a * n - mat).norm_l1() < 1.0e-8);
+        match mat.iwasawa_decomposition_with_tolerance(1.0e-4) {
+            Err(DecompositionError::RankDeficient { column, magnitude }) => {
+                assert_eq!(column, 3);
+                assert!(magnitude <= 1.0e-4 * mat[3].magnitude());
+            }
+            res => panic!("{res:?}"),
+        }
+    });
+}
+
+#[test]
+fn iwasawa_ill_conditioned() {
+    const EPS: f64 = 1.0e-12;
+    (0..1000).for_each(|_i| {
+        (0..=8).for_each(|e| {
+            // condition number 10^e
+            let cond = f64::powi(10.0, e);
+            let u = random_matrix4().qr().0;
+            let v = random_matrix4().qr().0;
+            let sigma = Vector4::new(
+                1.0,
+                f64::powf(cond, -1.0 / 3.0),
+                f64::powf(cond, -2.0 / 3.0),
+                1.0 / cond,
+            );
+            let mat = u * Matrix4::from_diagonal(sigma) * v.transpose();
+            let (k, a, n) = mat.iwasawa_decomposition().unwrap();
+            let k_err = k.transpose() * k - Matrix4::identity();
+            assert!(k_err.norm_l1() < EPS, "{mat:?}\n{k:?}\n{k_err:?}");
+            assert!(
+                (k * a * n - mat).norm_l1() < EPS,
+                "{mat:?}\n{k:?}\n{a:?}\n{n:?}"
+            );
+
+            let angle = Rad(2.0 * std::f64::consts::PI * rand::random::<f64>());
+            let u = Matrix3::from_axis_angle(random_vector3().normalize(), angle);
+            let angle = Rad(2.0 * std::f64::consts::PI * rand::random::<f64>());
+            let vt = Matrix3::from_axis_angle(random_vector3().normalize(), angle);
+            let sigma = Vector3::new(1.0, 1.0 / f64::sqrt(cond), 1.0 / cond);
+            let mat = u * Matrix3::from_diagonal(sigma) * vt;
+            let (k, a, n) = mat.iwasawa_decomposition().unwrap();
+            let k_err = k.transpose() * k - Matrix3::identity();
+            assert!(k_err.norm_l1() < EPS, "{mat:?}\n{k:?}\n{k_err:?}");
+            assert!(
+                (k * a * n - mat).norm_l1() < EPS,
+                "{mat:?}\n{k:?}\n{a:?}\n{n:?}"
+            );
+        });
+    });
+}
+
+fn check_svd4(mat: Matrix4<f64>) {
+    const EPS: f64 = 1.0e-10;
+    let (u, sigma, vt) = mat.svd();
+    let u_err = u.transpose() * u - Matrix4::identity();
+    let v_err = vt * vt.transpose() - Matrix4::identity();
+    let res = mat - u * sigma * vt;
+    assert!(u_err.norm_l1() < EPS, "{mat:?}\n{u:?}\n{sigma:?}\n{vt:?}");
+    assert!(v_err.norm_l1() < EPS, "{mat:?}\n{u:?}\n{sigma:?}\n{vt:?}");
+    assert!(res.norm_l1() < EPS, "{mat:?}\n{u:?}\n{sigma:?}\n{vt:?}");
+    assert!(sigma[3][3] >= 0.0, "{sigma:?}");
+    (0..3).for_each(|i| assert!(sigma[i][i] >= sigma[i + 1][i + 1], "{sigma:?}"));
+}
+
+#[test]
+fn svd() {
+    (0..10000).for_each(|_i| {
+        let mut mat = random_matrix4();
+        check_svd4(mat);
+        // rank-deficient
+        mat[3] = mat[0] * 2.0 - mat[1];
+        check_svd4(mat);
+        mat[2] = mat[0];
+        check_svd4(mat);
+        let (_, sigma, _) = mat.svd();
+        assert!(sigma[2][2] < 1.0e-10 && sigma[3][3] < 1.0e-10, "{sigma:?}");
+    });
+
+    // zero matrix
+    check_svd4(Matrix4::zero());
+
+    // small singular values are not lost
+    let p = Matrix3::from_angle_x(Rad(0.3)) * Matrix3::from_angle_y(Rad(1.2));
+    let q = Matrix3::from_angle_z(Rad(2.1)) * Matrix3::from_angle_x(Rad(-0.7));
+    let mat = p * Matrix3::from_diagonal(Vector3::new(1.0, 1.0e-6, 1.0e-9)) * q;
+    let (_, sigma, _) = mat.svd();
+    assert!(f64::abs(sigma[1][1] / 1.0e-6 - 1.0) < 1.0e-8, "{sigma:?}");
+    assert!(f64::abs(sigma[2][2] / 1.0e-9 - 1.0) < 1.0e-5, "{sigma:?}");
+
+    let (u, sigma, vt) = Matrix2::new(1.0, 2.0, 2.0, 4.0).svd();
+    assert!((u * sigma * vt - Matrix2::new(1.0, 2.0, 2.0, 4.0)).norm_l1() < 1.0e-10);
+    assert!(sigma[1][1] < 1.0e-10);
+}
+
+#[test]
+fn svd_extreme_scale() {
+    [1.0e200, 1.0e-200].into_iter().for_each(|scale| {
+        let mat = random_matrix4();
+        let (u, sigma, vt) = (mat * scale).svd();
+        let (u0, sigma0, vt0) = mat.svd();
+        assert!((u - u0).norm_l1() < 1.0e-10, "{mat:?}");
+        assert!((vt - vt0).norm_l1() < 1.0e-10, "{mat:?}");
+        assert!((sigma / scale - sigma0).norm_l1() < 1.0e-10, "{mat:?}");
+    });
+}
+
+#[test]
+fn svd_non_finite() {
+    [f64::NAN, f64::INFINITY, f64::NEG_INFINITY]
+        .into_iter()
+        .for_each(|x| {
+            let mut mat = Matrix4::<f64>::identity();
+            mat[2][1] = x;
+            let (u, sigma, vt) = mat.svd();
+            assert!(u[0][0].is_nan() && sigma[0][0].is_nan() && vt[0][0].is_nan());
+            assert!(mat.pseudo_inverse(1.0e-10).0[0][0].is_nan());
+            let (x, _) = mat.least_squares(Vector4::new(1.0, 2.0, 3.0, 4.0));
+            assert!(x.x.is_nan());
+            assert!(mat.null_space(1.0e-10).iter().all(|v| v.x.is_nan()));
+            assert!(mat.cond_l2().is_nan());
+            assert!(mat.norm_nuclear().is_nan());
+            assert!(mat.norm_schatten(3.0).is_nan());
+            assert!(mat.norm_lp(2.0).is_nan());
+            assert!(mat.norm_lp(3.0).is_nan());
+        });
+}
+
+#[test]
+fn polar_decomposition() {
+    const EPS: f64 = 1.0e-8;
+    (0..10000).for_each(|_i| {
+        let mat = Matrix3::new(
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+        );
+        if !mat.is_invertible() {
+            return;
+        }
+        let (r, s) = mat.polar_decomposition(false).unwrap();
+        let r_err = r * r.transpose() - Matrix3::identity();
+        assert!(r_err.norm_l1() < EPS, "{_i} {mat:?}\n{r:?}\n{s:?}");
+        assert!(
+            (s - s.transpose()).norm_l1() < EPS,
+            "{_i} {mat:?}\n{r:?}\n{s:?}"
+        );
+        assert!(s.symmetric_eigen().0.x > -EPS, "{_i} {mat:?}\n{r:?}\n{s:?}");
+        assert!((mat - r * s).norm_l1() < EPS, "{_i} {mat:?}\n{r:?}\n{s:?}");
+        assert!(r.determinant() * mat.determinant() > 0.0);
+
+        let (r, s) = mat.polar_decomposition(true).unwrap();
+        let r_err = r * r.transpose() - Matrix3::identity();
+        assert!(r_err.norm_l1() < EPS, "{_i} {mat:?}\n{r:?}\n{s:?}");
+        assert!(f64::abs(r.determinant() - 1.0) < EPS, "{_i} {mat:?}\n{r:?}");
+        assert!(
+            (s - s.transpose()).norm_l1() < EPS,
+            "{_i} {mat:?}\n{r:?}\n{s:?}"
+        );
+        assert!((mat - r * s).norm_l1() < EPS, "{_i} {mat:?}\n{r:?}\n{s:?}");
+    });
+
+    let mat = Matrix4::from_nonuniform_scale(1.0, 2.0, 0.0);
+    match mat.polar_decomposition(true) {
+        Err(DecompositionError::RankDeficient { column, magnitude }) => {
+            assert_eq!(column, 3);
+            assert_eq!(magnitude, 0.0);
+        }
+        res => panic!("{res:?}"),
+    }
+}
+
+/// Returns `mat` and its modifications of the ranks 3, 2 and 1, paired with the ranks,
+/// by replacing the columns with the linear combinations of the others.
+fn rank_deficient4(mat: Matrix4<f64>) -> [(Matrix4<f64>, usize); 4] {
+    let mut rank3 = mat;
+    rank3[1] = mat[3] * 0.5 - mat[0] * 2.0;
+    let mut rank2 = rank3;
+    rank2[2] = mat[0] * 3.0;
+    let rank1 = Matrix4::from_cols(mat[0], mat[0] * -2.0, mat[0] * 3.0, Vector4::zero());
+    [(mat, 4), (rank3, 3), (rank2, 2), (rank1, 1)]
+}
+
+fn check_qr4(mat: Matrix4<f64>, rank: usize) {
+    const EPS: f64 = 1.0e-10;
+    let (q, r) = mat.qr();
+    let q_err = q.transpose() * q - Matrix4::identity();
+    assert!(q_err.norm_l1() < EPS, "{mat:?}\n{q:?}\n{r:?}");
+    assert!((mat - q * r).norm_l1() < EPS, "{mat:?}\n{q:?}\n{r:?}");
+    (0..4).for_each(|j| {
+        assert!(r[j][j] >= 0.0, "{r:?}");
+        (j + 1..4).for_each(|i| assert_eq!(r[j][i], 0.0, "{r:?}"));
+    });
+
+    let (q, r, p, rank0) = mat.qr_pivoted();
+    assert_eq!(rank, rank0, "{mat:?}\n{r:?}");
+    let q_err = q.transpose() * q - Matrix4::identity();
+    assert!(q_err.norm_l1() < EPS, "{mat:?}\n{q:?}\n{r:?}");
+    assert!((mat * p - q * r).norm_l1() < EPS, "{mat:?}\n{q:?}\n{r:?}");
+    assert_eq!(p.transpose() * p, Matrix4::identity());
+    (0..4).for_each(|j| {
+        assert!(r[j][j] >= 0.0, "{r:?}");
+        (j + 1..4).for_each(|i| assert_eq!(r[j][i], 0.0, "{r:?}"));
+    });
+    (0..3).for_each(|j| assert!(r[j][j] >= r[j + 1][j + 1], "{r:?}"));
+}
+
+#[test]
+fn qr() {
+    (0..10000).for_each(|_i| {
+        rank_deficient4(random_matrix4())
+            .into_iter()
+            .for_each(|(mat, rank)| check_qr4(mat, rank));
+    });
+    check_qr4(Matrix4::zero(), 0);
+
+    let (q, r) = Matrix2::new(0.0, 0.0, 1.0, 1.0).qr();
+    assert!((q * r - Matrix2::new(0.0, 0.0, 1.0, 1.0)).norm_l1() < 1.0e-10);
+
+    // non-finite entries
+    [f64::NAN, f64::INFINITY, f64::NEG_INFINITY]
+        .into_iter()
+        .for_each(|x| {
+            let mut mat = random_matrix4();
+            mat[2][1] = x;
+            let (q, r) = mat.qr();
+            assert!(q[0][0].is_nan() && r[0][0].is_nan());
+            let (q, r, p, rank) = mat.qr_pivoted();
+            assert!(q[0][0].is_nan() && r[0][0].is_nan());
+            assert_eq!(p, Matrix4::identity());
+            assert_eq!(rank, 0);
+        });
+}
+
+#[test]
+fn lu() {
+    const EPS: f64 = 1.0e-8;
+    (0..10000).for_each(|_i| {
+        let mat = random_matrix4();
+        let (p, l, u) = mat.lu().unwrap();
+        assert!(
+            (p * mat - l * u).norm_l1() < EPS,
+            "{mat:?}\n{p:?}\n{l:?}\n{u:?}"
+        );
+        assert_eq!(p.transpose() * p, Matrix4::identity());
+        (0..4).for_each(|j| {
+            assert_eq!(l[j][j], 1.0);
+            (0..j).for_each(|i| assert_eq!(l[j][i], 0.0));
+            (j + 1..4).for_each(|i| assert!(f64::abs(l[j][i]) <= 1.0));
+            (j + 1..4).for_each(|i| assert_eq!(u[j][i], 0.0));
+        });
+
+        let b = Vector4::new(
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+        );
+        let x = mat.solve(b).unwrap();
+        let res = mat * x - b;
+        assert!(
+            res.magnitude() < EPS * mat.norm_l1() * x.magnitude(),
+            "{mat:?}\n{x:?}"
+        );
+
+        let b = random_matrix4();
+        let x = mat.solve_matrix(b).unwrap();
+        let res = mat * x - b;
+        assert!(
+            res.norm_l1() < EPS * mat.norm_l1() * x.norm_l1(),
+            "{mat:?}\n{x:?}"
+        );
+    });
+
+    // singular and nearly singular
+    let mut mat = random_matrix4();
+    mat[2] = Vector4::zero();
+    assert!(mat.solve(Vector4::unit_x()).is_err());
+    #[rustfmt::skip]
+    let mat = Matrix3::new(
+        1.0, 1.0, 0.0,
+        1.0, 1.0 + 4.0e-16, 0.0,
+        0.0, 0.0, 1.0,
+    );
+    match mat.lu() {
+        Err(DecompositionError::RankDeficient { column, magnitude }) => {
+            assert_eq!(column, 1);
+            assert!(magnitude > 0.0);
+        }
+        res => panic!("{res:?}"),
+    }
+    assert!(Matrix2::<f64>::zero()
+        .solve_matrix(Matrix2::identity())
+        .is_err());
+}
+
+#[test]
+fn cholesky() {
+    const EPS: f64 = 1.0e-8;
+    (0..10000).for_each(|_i| {
+        let b = random_matrix4();
+        let mat = b.transpose() * b + Matrix4::identity() * 0.1;
+        let l = mat.cholesky().unwrap();
+        assert!((l * l.transpose() - mat).norm_l1() < EPS, "{mat:?}\n{l:?}");
+        let (l1, d) = mat.ldlt().unwrap();
+        assert!(
+            (l1 * d * l1.transpose() - mat).norm_l1() < EPS,
+            "{mat:?}\n{l1:?}\n{d:?}"
+        );
+        (0..4).for_each(|j| {
+            assert!(l[j][j] > 0.0 && d[j][j] > 0.0);
+            assert_eq!(l1[j][j], 1.0);
+            (0..j).for_each(|i| assert!(l[j][i] == 0.0 && l1[j][i] == 0.0));
+        });
+
+        // positive semi-definite
+        let mut b = b;
+        b[3] = b[0] - b[1] * 2.0;
+        let mat = b.transpose() * b;
+        let (l, d) = mat.ldlt().unwrap();
+        assert!(
+            (l * d * l.transpose() - mat).norm_l1() < EPS,
+            "{mat:?}\n{l:?}\n{d:?}"
+        );
+        assert!(d[3][3] < EPS, "{d:?}");
+    });
+
+    // indefinite
+    let mat = Matrix3::from_diagonal(Vector3::new(1.0, -1.0, 2.0));
+    let err = DecompositionError::NotPositiveDefinite {
+        pivot: 1,
+        value: -1.0,
+    };
+    assert_eq!(mat.cholesky(), Err(err));
+    assert_eq!(mat.ldlt(), Err(err));
+    // semi-definite is not allowed in Cholesky decomposition
+    let mat = Matrix3::from_diagonal(Vector3::new(1.0, 0.0, 2.0));
+    assert!(mat.cholesky().is_err());
+    let (l, d) = mat.ldlt().unwrap();
+    assert_eq!(l * d * l.transpose(), mat);
+    // zero pivot with non-zero column
+    let mat = Matrix2::new(0.0, 1.0, 1.0, 0.0);
+    assert!(mat.ldlt().is_err());
+    // the coupling of the small diagonal entries is not neglected
+    let mat = Matrix3::new(1.0e4, 0.0, 0.0, 0.0, 1.0e-5, 5.0e-6, 0.0, 5.0e-6, 1.0e-5);
+    let (l, d) = mat.ldlt().unwrap();
+    assert!(
+        (l * d * l.transpose() - mat).norm_l1() < 1.0e-12 * mat.norm_l1(),
+        "{l:?}\n{d:?}"
+    );
+    assert!(f64::abs(l[1][2] - 0.5) < 1.0e-12, "{l:?}");
+}
+
+fn check_schur4(mat: Matrix4<f64>) {
+    const EPS: f64 = 1.0e-10;
+    let (q, t) = mat.schur().unwrap();
+    let q_err = q.transpose() * q - Matrix4::identity();
+    assert!(q_err.norm_l1() < EPS, "{mat:?}\n{q:?}\n{t:?}");
+    let scale = f64::max(mat.norm_l1(), 1.0);
+    assert!(
+        (q * t * q.transpose() - mat).norm_l1() < EPS * scale,
+        "{mat:?}\n{q:?}\n{t:?}"
+    );
+    (0..4).for_each(|j| (j + 2..4).for_each(|i| assert_eq!(t[j][i], 0.0, "{t:?}")));
+    (0..3).for_each(|j| {
+        if t[j][j + 1] != 0.0 {
+            // standardized 2x2 block of complex eigen values
+            assert_eq!(t[j][j], t[j + 1][j + 1], "{t:?}");
+            assert!(t[j][j + 1] * t[j + 1][j] < 0.0, "{t:?}");
+            if j + 2 < 4 {
+                assert_eq!(t[j + 1][j + 2], 0.0, "{t:?}");
+            }
+        }
+    });
+}
+
+#[test]
+fn schur() {
+    (0..10000).for_each(|_i| check_schur4(random_matrix4()));
+    (0..1000).for_each(|_i| {
+        // repeated eigen values and rank deficiency
+        let mut mat = random_matrix4();
+        mat[1] = mat[0] * 2.0;
+        check_schur4(mat);
+        check_schur4(mat * mat.transpose());
+    });
+    check_schur4(Matrix4::zero());
+    check_schur4(Matrix4::identity());
+    // cyclic permutation: eigen values 1, -1, i, -i
+    check_schur4(Matrix4::from_cols(
+        Vector4::unit_y(),
+        Vector4::unit_z(),
+        Vector4::unit_w(),
+        Vector4::unit_x(),
+    ));
+    // Jordan block
+    #[rustfmt::skip]
+    check_schur4(Matrix4::new(
+        2.0, 0.0, 0.0, 0.0,
+        1.0, 2.0, 0.0, 0.0,
+        0.0, 1.0, 2.0, 0.0,
+        0.0, 0.0, 1.0, 2.0,
+    ));
+
+    // rotation: a complex pair and a real eigen value
+    let mat = Matrix3::from_axis_angle(Vector3::new(0.6, 0.0, 0.8), Rad(1.0));
+    let (q, t) = mat.schur().unwrap();
+    assert!((q * t * q.transpose() - mat).norm_l1() < 1.0e-10);
+    let mut eigens = mat.eigenvalues_by_schur();
+    eigens.sort_by(|x, y| x.im.partial_cmp(&y.im).unwrap());
+    assert!((eigens[0] - Complex::new(f64::cos(1.0), -f64::sin(1.0))).norm() < 1.0e-10);
+    assert!((eigens[1] - 1.0).norm() < 1.0e-10);
+    assert!((eigens[2] - Complex::new(f64::cos(1.0), f64::sin(1.0))).norm() < 1.0e-10);
+
+    let mat = Matrix2::new(0.0, 1.0, 0.0, 0.0);
+    let (q, t) = mat.schur().unwrap();
+    assert!((q * t * q.transpose() - mat).norm_l1() < 1.0e-10);
+    assert_eq!(t[0][1], 0.0);
+
+    // non-finite entries
+    let mut mat = random_matrix4();
+    mat[1][3] = f64::NAN;
+    assert_eq!(mat.schur(), Err(DecompositionError::NonFinite));
+    assert!(mat.eigenvalues_by_schur().iter().all(|e| e.re.is_nan()));
+}
+
+#[test]
+fn hessenberg() {
+    const EPS: f64 = 1.0e-10;
+    (0..10000).for_each(|_i| {
+        let mat = random_matrix4();
+        let (q, h) = mat.hessenberg();
+        assert!(
+            (q.transpose() * q - Matrix4::identity()).norm_l1() < EPS,
+            "{mat:?}\n{q:?}"
+        );
+        assert!(
+            (q * h * q.transpose() - mat).norm_l1() < EPS,
+            "{mat:?}\n{q:?}\n{h:?}"
+        );
+        (0..4).for_each(|j| (j + 2..4).for_each(|i| assert_eq!(h[j][i], 0.0, "{h:?}")));
+
+        let sym = mat + mat.transpose();
+        let (q, t) = sym.tridiagonal();
+        assert!(
+            (q.transpose() * q - Matrix4::identity()).norm_l1() < EPS,
+            "{sym:?}\n{q:?}"
+        );
+        assert!(
+            (q * t * q.transpose() - sym).norm_l1() < EPS,
+            "{sym:?}\n{q:?}\n{t:?}"
+        );
+        assert_eq!(t, t.transpose());
+        (0..4).for_each(|j| (j + 2..4).for_each(|i| assert_eq!(t[j][i], 0.0, "{t:?}")));
+        // only the symmetric part is referred
+        let (q0, t0) = (mat * 2.0).tridiagonal();
+        assert!((q0 - q).norm_l1() < EPS && (t0 - t).norm_l1() < EPS);
+    });
+
+    // already reduced matrices
+    let (q, h) = Matrix4::<f64>::identity().hessenberg();
+    assert_eq!((q, h), (Matrix4::identity(), Matrix4::identity()));
+    let mat = Matrix3::new(1.0, 2.0, 0.0, 2.0, 3.0, 4.0, 0.0, 4.0, 5.0);
+    let (q, t) = mat.tridiagonal();
+    assert_eq!((q, t), (Matrix3::identity(), mat));
+}
+
+#[test]
+fn decompose_affine() {
+    const EPS: f64 = 1.0e-8;
+    (0..10000).for_each(|_i| {
+        let axis = random_vector3().normalize();
+        let components = AffineComponents {
+            translation: random_vector3(),
+            rotation: Quaternion::from_axis_angle(axis, Rad(rand::random::<f64>() * 3.0)),
+            scale: random_vector3().map(|x| f64::abs(x) + 0.1),
+            shear: random_vector3(),
+        };
+        let mat = components.recompose();
+        let res = mat.decompose_affine().unwrap();
+        assert!((res.recompose() - mat).norm_l1() < EPS, "{mat:?}\n{res:?}");
+        assert!((res.translation - components.translation).magnitude() < EPS);
+        assert!(f64::abs(res.rotation.dot(components.rotation)) > 1.0 - EPS);
+        assert!((res.scale - components.scale).magnitude() < EPS);
+        assert!((res.shear - components.shear).magnitude() < EPS);
+
+        // reflection
+        let refl = Matrix4::from_nonuniform_scale(1.0, -1.0, 1.0) * mat;
+        let res = refl.decompose_affine().unwrap();
+        assert!(
+            (res.recompose() - refl).norm_l1() < EPS,
+            "{refl:?}\n{res:?}"
+        );
+        assert!(res.scale.x > 0.0 && res.scale.y > 0.0 && res.scale.z < 0.0);
+        assert!(f64::abs(res.rotation.magnitude() - 1.0) < EPS);
+
+        // not affine
+        let mut proj = mat;
+        proj[0][3] = 0.5;
+        assert_eq!(proj.decompose_affine(), Err(DecompositionError::NotAffine));
+    });
+
+    // singular linear part
+    let mat = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+        * Matrix4::from_nonuniform_scale(1.0, 0.0, 2.0);
+    match mat.decompose_affine() {
+        Err(DecompositionError::RankDeficient { column, magnitude }) => {
+            assert_eq!(column, 1);
+            assert_eq!(magnitude, 0.0);
+        }
+        res => panic!("{res:?}"),
+    }
+    let mut mat = Matrix4::<f64>::identity();
+    mat[3][0] = f64::NAN;
+    assert_eq!(mat.decompose_affine(), Err(DecompositionError::NonFinite));
+}
+
+#[test]
+fn rq() {
+    const EPS: f64 = 1.0e-10;
+    (0..10000).for_each(|_i| {
+        let mut mat = random_matrix4();
+        (0..2).for_each(|_| {
+            let (r, q) = mat.rq();
+            assert!(
+                (q.transpose() * q - Matrix4::identity()).norm_l1() < EPS,
+                "{mat:?}\n{q:?}"
+            );
+            assert!((r * q - mat).norm_l1() < EPS, "{mat:?}\n{r:?}\n{q:?}");
+            (0..4).for_each(|j| {
+                assert!(r[j][j] >= 0.0, "{r:?}");
+                (j + 1..4).for_each(|i| assert_eq!(r[j][i], 0.0, "{r:?}"));
+            });
+            // rank-deficient
+            mat = mat.transpose();
+            mat[2] = mat[0] * 2.0 - mat[3];
+            mat = mat.transpose();
+        });
+    });
+}
+
+#[test]
+fn camera() {
+    const EPS: f64 = 1.0e-8;
+    (0..10000).for_each(|_i| {
+        let v = random_vector3();
+        let k = Matrix3::new(
+            f64::abs(v.x) * 100.0 + 10.0,
+            0.0,
+            0.0,
+            v.y,
+            f64::abs(v.z) * 100.0 + 10.0,
+            0.0,
+            rand::random::<f64>() * 640.0,
+            rand::random::<f64>() * 480.0,
+            1.0,
+        );
+        let axis = random_vector3().normalize();
+        let r = Matrix3::from_axis_angle(axis, Rad(rand::random::<f64>() * 3.0));
+        let c = Point3::from_vec(random_vector3());
+        let lambda = 10.0 * rand::random::<f64>() - 5.0;
+        let (m, t) = (k * r * lambda, -(k * r * c.to_vec()) * lambda);
+        let (k0, r0, c0) = camera::decompose_projection(m, t).unwrap();
+        assert!((k0 - k).norm_l1() < EPS * k.norm_l1(), "{k:?}\n{k0:?}");
+        assert!((r0 - r).norm_l1() < EPS, "{r:?}\n{r0:?}");
+        assert!((c0 - c).magnitude() < EPS, "{c:?}\n{c0:?}");
+
+        // the last row is ignored
+        let w = random_vector3();
+        let mat = Matrix4::from_cols(
+            m.x.extend(w.x),
+            m.y.extend(w.y),
+            m.z.extend(w.z),
+            t.extend(1.0),
+        );
+        let (k1, r1, c1) = camera::decompose_projection_matrix4(mat).unwrap();
+        assert_eq!((k0, r0, c0), (k1, r1, c1));
+    });
+
+    // singular projection
+    let m = Matrix3::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0);
+    let res = camera::decompose_projection(m, Vector3::unit_z());
+    assert!(matches!(res, Err(DecompositionError::RankDeficient { .. })));
+}
+
+fn check_pseudo_inverse4(mat: Matrix4<f64>, rank: usize) {
+    const EPS: f64 = 1.0e-12;
+    let (p, rank0) = mat.pseudo_inverse(1.0e-10);
+    assert_eq!(rank, rank0, "{mat:?}");
+    // the errors grow with the condition number
+    let scale = (1.0 + mat.norm_l1()) * (1.0 + p.norm_l1());
+    let eps = EPS * scale * scale;
+    assert!((mat * p * mat - mat).norm_l1() < eps, "{mat:?}\n{p:?}");
+    assert!((p * mat * p - p).norm_l1() < eps, "{mat:?}\n{p:?}");
+    assert!(
+        ((mat * p).transpose() - mat * p).norm_l1() < eps,
+        "{mat:?}\n{p:?}"
+    );
+    assert!(
+        ((p * mat).transpose() - p * mat).norm_l1() < eps,
+        "{mat:?}\n{p:?}"
+    );
+
+    let b = random_vector4();
+    let (x, rank0) = mat.least_squares(b);
+    assert_eq!(rank, rank0, "{mat:?}");
+    let eps = eps * (1.0 + x.magnitude());
+    // normal equation
+    assert!(
+        (mat.transpose() * (mat * x - b)).magnitude() < eps,
+        "{mat:?}\n{x:?}"
+    );
+    // minimum norm: x is orthogonal to the null space
+    assert!((p * mat * x - x).magnitude() < eps, "{mat:?}\n{x:?}");
+}
+
+#[test]
+fn pseudo_inverse() {
+    (0..10000).for_each(|_i| {
+        let mat = random_matrix4();
+        let (p, _) = mat.pseudo_inverse(1.0e-10);
+        assert!((p - mat.invert().unwrap()).norm_l1() < 1.0e-8 * p.norm_l1());
+        let b = random_vector4();
+        let x = mat.least_squares(b).0;
+        assert!((x - mat.solve(b).unwrap()).magnitude() < 1.0e-8 * x.magnitude());
+        rank_deficient4(mat)
+            .into_iter()
+            .for_each(|(mat, rank)| check_pseudo_inverse4(mat, rank));
+    });
+    check_pseudo_inverse4(Matrix4::zero(), 0);
+
+    // the tolerance is relative to the maximum singular value
+    let mat = Matrix3::from_diagonal(Vector3::new(1.0e6, 1.0, 1.0e-6));
+    assert_eq!(mat.pseudo_inverse(1.0e-13).1, 3);
+    let (p, rank) = mat.pseudo_inverse(1.0e-9);
+    assert_eq!(rank, 2);
+    assert_eq!(p, Matrix3::from_diagonal(Vector3::new(1.0e-6, 1.0, 0.0)));
+}
+
+fn check_subspaces4(mat: Matrix4<f64>, rank: usize) {
+    const EPS: f64 = 1.0e-10;
+    assert_eq!(mat.rank(1.0e-10), rank, "{mat:?}");
+    let null = mat.null_space(1.0e-10);
+    let range = mat.column_space(1.0e-10);
+    assert_eq!(null.len(), 4 - rank, "{mat:?}");
+    assert_eq!(range.len(), rank, "{mat:?}");
+    let scale = f64::max(mat.norm_l1(), 1.0);
+    null.iter()
+        .for_each(|v| assert!((mat * v).magnitude() < EPS * scale, "{mat:?}\n{null:?}"));
+    // the columns are in the range
+    (0..4).for_each(|j| {
+        let proj = range
+            .iter()
+            .fold(Vector4::zero(), |sum, u| sum + u * u.dot(mat[j]));
+        assert!(
+            (proj - mat[j]).magnitude() < EPS * scale,
+            "{mat:?}\n{range:?}"
+        );
+    });
+    [null, range].iter().for_each(|basis| {
+        basis.iter().enumerate().for_each(|(i, u)| {
+            basis.iter().enumerate().for_each(|(j, v)| {
+                let delta = if i == j { 1.0 } else { 0.0 };
+                assert!(f64::abs(u.dot(*v) - delta) < EPS, "{basis:?}");
+            });
+        });
+    });
+}
+
+#[test]
+fn fundamental_subspaces() {
+    (0..10000).for_each(|_i| {
+        rank_deficient4(random_matrix4())
+            .into_iter()
+            .for_each(|(mat, rank)| check_subspaces4(mat, rank));
+    });
+    check_subspaces4(Matrix4::zero(), 0);
+
+    // rotation axes
+    (0..10000).for_each(|_i| {
+        let axis = random_vector3().normalize();
+        let angle = Rad(0.1 + 3.0 * rand::random::<f64>());
+        let rot = Matrix3::from_axis_angle(axis, angle);
+        let null = (rot - Matrix3::identity()).null_space(1.0e-8);
+        assert_eq!(null.len(), 1);
+        assert!(f64::abs(null[0].dot(axis)) > 1.0 - 1.0e-10);
+    });
+
+    let mat = Matrix2::new(1.0, 1.0, 1.0, 1.0);
+    assert_eq!(mat.null_space(1.0e-12).len(), 1);
+    assert_eq!(mat.column_space(1.0e-12).len(), 1);
+}
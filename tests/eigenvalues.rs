@@ -1,7 +1,11 @@
 use cgmath::*;
 use matext4cgmath::*;
+use num_complex::Complex;
 use std::f64::consts::PI;
 
+mod common;
+use common::*;
+
 #[test]
 fn matrix2() {
     const EPS: f64 = 1.0e-10;
@@ -35,14 +39,6 @@ fn random_unit3() -> Vector3<f64> {
     Vector3::new(r * f64::cos(theta), r * f64::sin(theta), z)
 }
 
-fn random_vector3() -> Vector3<f64> {
-    Vector3::new(
-        10.0 * rand::random::<f64>() - 5.0,
-        10.0 * rand::random::<f64>() - 5.0,
-        10.0 * rand::random::<f64>() - 5.0,
-    )
-}
-
 #[test]
 fn matrix3() {
     const EPS: f64 = 1.0e-8;
@@ -85,3 +81,396 @@ fn matrix4() {
         assert!(any, "{eigens:?}");
     });
 }
+
+fn residual3(mat: Matrix3<f64>, e: Complex<f64>, v: Vector3<Complex<f64>>) -> f64 {
+    (0..3)
+        .map(|i| {
+            let mv = (0..3).fold(Complex::from(0.0), |sum, j| sum + v[j] * mat[j][i]);
+            (mv - e * v[i]).norm()
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn eigenvectors3() {
+    const EPS: f64 = 1.0e-8;
+
+    // random
+    (0..1000).for_each(|_i| {
+        let diag = random_vector3();
+        let p = Matrix3::from_axis_angle(random_unit3(), Rad(2.0 * PI * rand::random::<f64>()));
+        let mat = p * Matrix3::from_diagonal(diag) * p.transpose();
+        mat.eigenvectors().into_iter().for_each(|(e, v)| {
+            let v = v.unwrap();
+            let res = residual3(mat, e, v);
+            assert!(res < EPS * mat.norm_l1(), "{mat:?} {e} {v:?} {res}");
+        });
+    });
+
+    // rotation: complex eigen vectors
+    let mat = Matrix3::from_axis_angle(random_unit3(), Rad(1.0));
+    mat.eigenvectors().into_iter().for_each(|(e, v)| {
+        assert!(residual3(mat, e, v.unwrap()) < EPS);
+    });
+
+    // repeated eigen values: basis of the eigen space
+    // The repeated roots of the characteristic polynomial are accurate up to the square root of epsilon.
+    let p = Matrix3::from_axis_angle(random_unit3(), Rad(2.0 * PI * rand::random::<f64>()));
+    let mat = p * Matrix3::from_diagonal(Vector3::new(2.0, 2.0, -1.0)) * p.transpose();
+    let pairs = mat.eigenvectors();
+    pairs.iter().for_each(|(e, v)| {
+        let res = residual3(mat, *e, v.unwrap());
+        assert!(res < 1.0e-6, "{pairs:?} {res}");
+    });
+    let vectors = pairs.map(|(_, v)| v.unwrap().map(|x| x.re));
+    let det = Matrix3::from_cols(vectors[0], vectors[1], vectors[2]).determinant();
+    assert!(f64::abs(det) > 0.5, "{pairs:?}");
+
+    // defective
+    #[rustfmt::skip]
+    let mat = Matrix3::new(
+        3.0, 0.0, 0.0,
+        1.0, 3.0, 0.0,
+        0.0, 0.0, 1.0,
+    );
+    let pairs = mat.eigenvectors();
+    assert_eq!(pairs.iter().filter(|(_, v)| v.is_none()).count(), 1);
+    pairs.iter().for_each(|(e, v)| {
+        if let Some(v) = v {
+            assert!(residual3(mat, *e, *v) < EPS);
+        }
+    });
+}
+
+#[test]
+fn eigenvectors4() {
+    const EPS: f64 = 1.0e-8;
+
+    #[rustfmt::skip]
+    let mat = Matrix4::new(
+        67.0, -6.0, -21.0, -55.0,
+        -654.0, 32.0, 138.0, 510.0,
+        507.0, -22.0, -101.0, -395.0,
+        -2.0, -4.0, -10.0, -2.0,
+    );
+    mat.eigenvectors().into_iter().for_each(|(e, v)| {
+        let v = v.unwrap();
+        (0..4).for_each(|i| {
+            let mv = (0..4).fold(Complex::from(0.0), |sum, j| sum + v[j] * mat[j][i]);
+            assert!((mv - e * v[i]).norm() < EPS * mat.norm_l1(), "{e} {v:?}");
+        });
+    });
+
+    let pairs = Matrix4::<f64>::identity().eigenvectors();
+    assert!(pairs.iter().all(|(_, v)| v.is_some()));
+}
+
+#[test]
+fn eigenvectors_non_finite() {
+    for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let mut mat = Matrix2::new(4.0, -2.0, 3.0, -1.0);
+        mat[1][0] = x;
+        assert!(mat.eigenvectors().iter().all(|(_, v)| v.is_none()));
+        let mut mat = Matrix3::from_diagonal(Vector3::new(1.0, 2.0, 3.0));
+        mat[0][2] = x;
+        assert!(mat.eigenvectors().iter().all(|(_, v)| v.is_none()));
+        let mut mat = Matrix4::<f64>::identity();
+        mat[3][3] = x;
+        assert!(mat.eigenvectors().iter().all(|(_, v)| v.is_none()));
+    }
+}
+
+#[test]
+fn eigenvectors_close_eigenvalues() {
+    const EPS: f64 = 1.0e-8;
+
+    // close but distinct eigen values are kept as they are.
+    let mat = Matrix3::from_diagonal(Vector3::new(1.0, 1.0 + 1.0e-6, 3.0));
+    let pairs = mat.eigenvectors();
+    let eigens = mat.eigenvalues();
+    pairs.iter().zip(eigens).for_each(|((e, v), e0)| {
+        assert_eq!(*e, e0);
+        assert!(residual3(mat, *e, v.unwrap()) < EPS, "{pairs:?}");
+    });
+    let mut values = pairs.map(|(e, _)| e.re);
+    values.sort_by(f64::total_cmp);
+    assert!(f64::abs(values[0] - 1.0) < EPS && f64::abs(values[1] - 1.0 - 1.0e-6) < EPS);
+
+    // diagonalizable, with close eigen values
+    let mat = Matrix2::new(1.0, 0.0, 1.0, 1.0 + 1.0e-7);
+    let pairs = mat.eigenvectors();
+    assert!(pairs[0].0 != pairs[1].0);
+    pairs.iter().for_each(|(e, v)| {
+        let v = v.unwrap();
+        let mv = Vector2::new(
+            v.x * mat[0][0] + v.y * mat[1][0],
+            v.x * mat[0][1] + v.y * mat[1][1],
+        );
+        assert!((mv.x - e * v.x).norm() < EPS && (mv.y - e * v.y).norm() < EPS);
+    });
+}
+
+fn random_orthogonal4() -> Matrix4<f64> {
+    random_matrix4().qr().0
+}
+
+#[test]
+fn symmetric_eigen3() {
+    const EPS: f64 = 1.0e-12;
+
+    // random
+    (0..1000).for_each(|_i| {
+        let mut diag: [f64; 3] = random_vector3().into();
+        let p = Matrix3::from_axis_angle(random_unit3(), Rad(2.0 * PI * rand::random::<f64>()));
+        let mat = p * Matrix3::from_diagonal(diag.into()) * p.transpose();
+        let (values, vectors) = mat.symmetric_eigen();
+        diag.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        assert!(
+            (values - Vector3::from(diag)).magnitude() < EPS,
+            "{values:?} {diag:?}"
+        );
+        let err = vectors.transpose() * vectors - Matrix3::identity();
+        assert!(err.norm_l1() < EPS, "{vectors:?}");
+        let err = mat * vectors - vectors * Matrix3::from_diagonal(values);
+        assert!(err.norm_l1() < EPS, "{mat:?} {values:?} {vectors:?}");
+    });
+
+    // nearly repeated eigen values
+    let p = Matrix3::from_axis_angle(random_unit3(), Rad(2.0 * PI * rand::random::<f64>()));
+    let diag = Vector3::new(1.0, 1.0 + 1.0e-10, 2.0);
+    let mat = p * Matrix3::from_diagonal(diag) * p.transpose();
+    let (values, vectors) = mat.symmetric_eigen();
+    assert!((values - diag).magnitude() < EPS, "{values:?}");
+    let err = vectors.transpose() * vectors - Matrix3::identity();
+    assert!(err.norm_l1() < EPS, "{vectors:?}");
+    let err = mat * vectors - vectors * Matrix3::from_diagonal(values);
+    assert!(err.norm_l1() < EPS, "{mat:?} {values:?} {vectors:?}");
+}
+
+#[test]
+fn symmetric_eigen4() {
+    const EPS: f64 = 1.0e-10;
+
+    (0..1000).for_each(|_i| {
+        let mut diag: [f64; 4] = random_vector4().into();
+        let p = random_orthogonal4();
+        let mat = p * Matrix4::from_diagonal(diag.into()) * p.transpose();
+        let (values, vectors) = mat.symmetric_eigen();
+        diag.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        assert!(
+            (values - Vector4::from(diag)).magnitude() < EPS,
+            "{values:?} {diag:?}"
+        );
+        let err = vectors.transpose() * vectors - Matrix4::identity();
+        assert!(err.norm_l1() < EPS, "{vectors:?}");
+        let err = mat * vectors - vectors * Matrix4::from_diagonal(values);
+        assert!(err.norm_l1() < EPS, "{mat:?} {values:?} {vectors:?}");
+    });
+
+    // non-finite entries
+    [f64::NAN, f64::INFINITY, f64::NEG_INFINITY]
+        .into_iter()
+        .for_each(|x| {
+            let mut mat = Matrix4::from_diagonal(random_vector4());
+            mat[1][2] = x;
+            mat[2][1] = x;
+            let (values, vectors) = mat.symmetric_eigen();
+            assert!(values.x.is_nan() && values.w.is_nan(), "{values:?}");
+            assert!(
+                vectors[0][0].is_nan() && vectors[3][3].is_nan(),
+                "{vectors:?}"
+            );
+            assert!(mat.log_norm_l2().is_nan());
+        });
+}
+
+#[test]
+fn eigenvalues_by_schur() {
+    const EPS: f64 = 1.0e-8;
+
+    (0..1000).for_each(|_i| {
+        let diag = random_vector3();
+        let p = Matrix3::from_axis_angle(random_unit3(), Rad(2.0 * PI * rand::random::<f64>()));
+        let ng = random_vector3();
+        #[rustfmt::skip]
+        let nilp = Matrix3::new(
+            1.0, 0.0, 0.0,
+            ng.x, 1.0, 0.0,
+            ng.y, ng.z, 1.0,
+        );
+        let mat = p * nilp * Matrix3::from_diagonal(diag) * (p * nilp).invert().unwrap();
+        let eigens = mat.eigenvalues_by_schur();
+        [diag.x, diag.y, diag.z].iter().for_each(|e| {
+            let any = eigens.iter().any(|x| (e - x).norm() < EPS);
+            assert!(any, "{mat:?} {diag:?} {eigens:?}");
+        });
+    });
+
+    #[rustfmt::skip]
+    let mat = Matrix4::new(
+        67.0, -6.0, -21.0, -55.0,
+        -654.0, 32.0, 138.0, 510.0,
+        507.0, -22.0, -101.0, -395.0,
+        -2.0, -4.0, -10.0, -2.0,
+    );
+    let eigens = mat.eigenvalues_by_schur();
+    [-12.0, -4.0, 4.0, 8.0].iter().copied().for_each(|x| {
+        let any = eigens.iter().any(|e| (e - x).norm() < 1.0e-10);
+        assert!(any, "{eigens:?}");
+    });
+
+    // non-normal matrices: orthogonally similar to upper triangular ones
+    (0..1000).for_each(|_i| {
+        let diag = Vector4::new(-3.0, -1.0, 1.0, 3.0);
+        let p = random_orthogonal4();
+        let mut mat = Matrix4::from_diagonal(diag);
+        (0..4).for_each(|j| (0..j).for_each(|i| mat[j][i] = random_vector4()[i]));
+        let mat = p * mat * p.transpose();
+        let eigens = mat.eigenvalues_by_schur();
+        [diag.x, diag.y, diag.z, diag.w].iter().for_each(|e| {
+            let any = eigens.iter().any(|x| (e - x).norm() < 1.0e-10);
+            assert!(any, "{mat:?} {eigens:?}");
+        });
+    });
+}
+
+#[test]
+fn spectral() {
+    const EPS: f64 = 1.0e-8;
+    (0..1000).for_each(|_i| {
+        let mat = Matrix4::from_cols(
+            random_vector4(),
+            random_vector4(),
+            random_vector4(),
+            random_vector4(),
+        );
+        let eigens = mat.eigenvalues_by_schur();
+        let (radius, abscissa) = (mat.spectral_radius(), mat.spectral_abscissa());
+        assert!(eigens
+            .iter()
+            .all(|e| e.norm() <= radius && e.re <= abscissa));
+        assert!(eigens.iter().any(|e| e.norm() == radius));
+        assert!(eigens.iter().any(|e| e.re == abscissa));
+        assert!(abscissa <= radius);
+        // the spectral radius is a lower bound of the operator norms
+        assert!(radius <= mat.norm_l1() * (1.0 + EPS) && radius <= mat.norm_linf() * (1.0 + EPS));
+        // the numerical abscissa
+        let omega = mat.numerical_abscissa();
+        assert_eq!(omega, mat.log_norm_l2());
+        assert!(abscissa <= omega + EPS);
+        (0..10).for_each(|_j| {
+            let x = random_vector4().normalize();
+            assert!(x.dot(mat * x) <= omega + EPS);
+        });
+        // stabilities
+        assert_eq!(mat.is_stable_continuous(), abscissa < 0.0);
+        assert_eq!(mat.is_stable_discrete(), radius < 1.0);
+        let shifted = mat - Matrix4::identity() * (abscissa + 0.5);
+        assert!(shifted.is_stable_continuous());
+        assert!(f64::abs(shifted.spectral_abscissa() + 0.5) < EPS);
+        let scaled = mat / (2.0 * radius);
+        assert!(scaled.is_stable_discrete());
+        assert!(f64::abs(scaled.spectral_radius() - 0.5) < EPS);
+    });
+
+    // normal matrices: the spectral radius equals to the L^2 operator norm,
+    // and the numerical abscissa equals to the spectral abscissa.
+    let mat = Matrix3::from_axis_angle(Vector3::new(1.0, 2.0, 2.0) / 3.0, Rad(1.0)) * 2.0;
+    assert!(f64::abs(mat.spectral_radius() - 2.0) < EPS);
+    assert!(f64::abs(mat.spectral_abscissa() - 2.0) < EPS);
+    assert!(f64::abs(mat.numerical_abscissa() - 2.0) < EPS);
+    assert!(!mat.is_stable_continuous() && !mat.is_stable_discrete());
+    let mat = Matrix3::from_diagonal(Vector3::new(-0.5, -0.9, 0.1));
+    assert_eq!(mat.spectral_radius(), 0.9);
+    assert_eq!(mat.spectral_abscissa(), 0.1);
+    assert!(!mat.is_stable_continuous() && mat.is_stable_discrete());
+
+    // slightly shifted nilpotent matrix: stable in the both senses, but transiently growing
+    #[rustfmt::skip]
+    let nilp = Matrix4::new(
+        0.0, 1.0, 2.0, 3.0,
+        0.0, 0.0, 4.0, 5.0,
+        0.0, 0.0, 0.0, 6.0,
+        0.0, 0.0, 0.0, 0.0,
+    );
+    let mat = nilp - Matrix4::identity() * 1.0e-3;
+    assert!(mat.is_stable_continuous() && mat.is_stable_discrete());
+    assert!(mat.numerical_abscissa() > 1.0);
+
+    // non-finite entries
+    [f64::NAN, f64::INFINITY, f64::NEG_INFINITY]
+        .into_iter()
+        .for_each(|x| {
+            let mut mat = Matrix3::<f64>::identity() * 0.5;
+            mat[0][1] = x;
+            assert!(mat.spectral_radius().is_nan());
+            assert!(mat.spectral_abscissa().is_nan());
+            assert!(mat.numerical_abscissa().is_nan());
+            assert!(!mat.is_stable_continuous() && !mat.is_stable_discrete());
+        });
+}
+
+#[test]
+fn characteristic_polynomial() {
+    const EPS: f64 = 1.0e-9;
+    (0..1000).for_each(|_i| {
+        let mat2 = Matrix2::new(
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+            10.0 * rand::random::<f64>() - 5.0,
+        );
+        let [a, b] = mat2.characteristic_polynomial();
+        let res = mat2 * mat2 + mat2 * a + Matrix2::identity() * b;
+        assert!(res.norm_l1() < EPS);
+
+        let mat3 = Matrix3::from_cols(random_vector3(), random_vector3(), random_vector3());
+        let [a, b, c] = mat3.characteristic_polynomial();
+        assert_eq!(a, -mat3.trace());
+        assert!(f64::abs(c + mat3.determinant()) < EPS);
+        let sq = mat3 * mat3;
+        let res = sq * mat3 + sq * a + mat3 * b + Matrix3::identity() * c;
+        assert!(res.norm_l1() < EPS);
+
+        let mat4 = Matrix4::from_cols(
+            random_vector4(),
+            random_vector4(),
+            random_vector4(),
+            random_vector4(),
+        );
+        let coef = mat4.characteristic_polynomial();
+        assert_eq!(coef[0], -mat4.trace());
+        assert!(f64::abs(coef[3] - mat4.determinant()) < EPS);
+        let sq = mat4 * mat4;
+        let res = sq * sq
+            + sq * mat4 * coef[0]
+            + sq * coef[1]
+            + mat4 * coef[2]
+            + Matrix4::identity() * coef[3];
+        assert!(res.norm_l1() < EPS);
+        // Vieta's formulas: the coefficients of (x - λ_1)(x - λ_2)(x - λ_3)(x - λ_4)
+        let prod = mat4.eigenvalues_by_schur().iter().fold(
+            [
+                Complex::from(1.0),
+                Complex::from(0.0),
+                Complex::from(0.0),
+                Complex::from(0.0),
+                Complex::from(0.0),
+            ],
+            |p, e| {
+                [
+                    p[0],
+                    p[1] - e * p[0],
+                    p[2] - e * p[1],
+                    p[3] - e * p[2],
+                    p[4] - e * p[3],
+                ]
+            },
+        );
+        (0..4).for_each(|k| assert!((prod[k + 1] - coef[k]).norm() < EPS, "{prod:?} {coef:?}"));
+        // invariant under similarity
+        let p = random_orthogonal4() * 2.0;
+        let coef0 = (p * mat4 * p.invert().unwrap()).characteristic_polynomial();
+        (0..4).for_each(|k| assert!(f64::abs(coef0[k] - coef[k]) < EPS));
+    });
+}
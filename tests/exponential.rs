@@ -0,0 +1,292 @@
+use cgmath::*;
+use matext4cgmath::*;
+
+mod common;
+use common::*;
+
+fn random_matrix3(range: f64) -> Matrix3<f64> {
+    let mut mat = Matrix3::zero();
+    (0..3)
+        .for_each(|i| (0..3).for_each(|j| mat[i][j] = range * (2.0 * rand::random::<f64>() - 1.0)));
+    mat
+}
+
+#[test]
+fn log_exp() {
+    const EPS: f64 = 1.0e-9;
+    (0..1000).for_each(|_i| {
+        let x = random_matrix3(1.0);
+        let res = x.exp().log().unwrap();
+        assert!((res - x).norm_l1() < EPS, "{x:?}\n{res:?}");
+
+        let x = random_matrix4() * 0.1;
+        let res = x.exp().log().unwrap();
+        assert!((res - x).norm_l1() < EPS, "{x:?}\n{res:?}");
+    });
+}
+
+#[test]
+fn exp_log() {
+    const EPS: f64 = 1.0e-9;
+    (0..1000).for_each(|_i| {
+        let p = random_matrix3(1.0);
+        let mat = p.transpose() * p + Matrix3::identity() * 0.1;
+        let res = mat.log().unwrap().exp();
+        assert!(
+            (res - mat).norm_l1() < EPS * mat.norm_l1(),
+            "{mat:?}\n{res:?}"
+        );
+    });
+
+    // rotation with the angle close to pi
+    let rot = Matrix3::from_angle_x(Rad(3.0)) * Matrix3::from_angle_z(Rad(2.0));
+    let res = rot.log().unwrap();
+    assert!((res + res.transpose()).norm_l1() < EPS, "{res:?}");
+    assert!((res.exp() - rot).norm_l1() < EPS, "{res:?}");
+}
+
+#[test]
+fn log_non_positive() {
+    assert!(Matrix3::from_diagonal(Vector3::new(1.0, -2.0, 3.0))
+        .log()
+        .is_none());
+    assert!(Matrix3::from_diagonal(Vector3::new(1.0, 0.0, 3.0))
+        .log()
+        .is_none());
+    assert!(Matrix3::from_angle_y(Rad(std::f64::consts::PI))
+        .log()
+        .is_none());
+    #[rustfmt::skip]
+    let jordan = Matrix4::new(
+        -1.0, 0.0, 0.0, 0.0,
+        1.0, -1.0, 0.0, 0.0,
+        0.0, 0.0, 2.0, 0.0,
+        0.0, 0.0, 1.0, 2.0,
+    );
+    assert!(jordan.log().is_none());
+    // non-finite entries
+    let mut mat = Matrix3::<f64>::identity();
+    mat[1][2] = f64::NAN;
+    assert!(mat.log().is_none());
+    mat[1][2] = f64::INFINITY;
+    assert!(mat.log().is_none());
+    // pure imaginary eigen values are allowed
+    let mat = Matrix2::from_angle(Rad(std::f64::consts::PI / 2.0));
+    let res = mat.log().unwrap();
+    assert!(
+        (res - Matrix2::new(0.0, 1.0, -1.0, 0.0) * std::f64::consts::PI / 2.0).norm_l1() < 1.0e-10
+    );
+}
+
+#[test]
+fn log_large_norm() {
+    const EPS: f64 = 1.0e-10;
+    // ill-conditioned exponential with the eigen values e^{16.7}, e^{0.198} and e^{-0.906}
+    let x = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    let res = x.exp().log().unwrap();
+    assert!((res - x).norm_l1() < 1.0e-7, "{res:?}");
+
+    (0..1000).for_each(|_i| {
+        let x = random_matrix3(1.0) + Matrix3::identity() * 20.0;
+        let res = x.exp().log().unwrap();
+        assert!((res - x).norm_l1() < EPS * x.norm_l1(), "{x:?}\n{res:?}");
+
+        let x = random_matrix4() * 0.1 - Matrix4::identity() * 30.0;
+        let res = x.exp().log().unwrap();
+        assert!((res - x).norm_l1() < EPS * x.norm_l1(), "{x:?}\n{res:?}");
+    });
+
+    // rotation with the angle close to pi, whose eigen values are close to the negative real axis
+    let rot = Matrix3::from_angle_z(Rad(std::f64::consts::PI - 1.0e-7));
+    let res = rot.log().unwrap();
+    assert!((res + res.transpose()).norm_l1() < EPS, "{res:?}");
+    assert!((res.exp() - rot).norm_l1() < 1.0e-8, "{res:?}");
+}
+
+#[test]
+fn log_f32() {
+    (0..1000).for_each(|_i| {
+        let x = random_matrix3(1.0).cast::<f32>().unwrap();
+        let res = x.exp().log().unwrap();
+        assert!((res - x).norm_l1() < 1.0e-4, "{x:?}\n{res:?}");
+    });
+
+    // The small eigen values of the exponential are lost in the rounding errors of f32,
+    // but the logarithm is consistent with the exponential.
+    let x = Matrix3::<f32>::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    let mat = x.exp();
+    let res = mat.log().unwrap();
+    assert!(
+        (res.exp() - mat).norm_l1() < 1.0e-4 * mat.norm_l1(),
+        "{res:?}"
+    );
+}
+
+#[test]
+fn exp_large_norm() {
+    const EPS: f64 = 1.0e-10;
+    let j = Matrix2::new(0.0, 1.0, -1.0, 0.0);
+    [10.0, 50.0, 100.0, 1000.0].iter().for_each(|&t| {
+        let res = (j * t).exp();
+        let ans = Matrix2::from_angle(Rad(t));
+        assert!((res - ans).norm_l1() < EPS * t, "{t} {res:?}");
+    });
+
+    // exp(P D P^{-1}) = P exp(D) P^{-1}
+    (0..1000).for_each(|_i| {
+        let p = random_matrix3(1.0) + Matrix3::identity() * 3.0;
+        let diag = Vector3::new(
+            60.0 * rand::random::<f64>() - 30.0,
+            60.0 * rand::random::<f64>() - 30.0,
+            60.0 * rand::random::<f64>() - 30.0,
+        );
+        let p_inv = p.invert().unwrap();
+        let mat = p * Matrix3::from_diagonal(diag) * p_inv;
+        let res = mat.exp();
+        let ans = p * Matrix3::from_diagonal(diag.map(f64::exp)) * p_inv;
+        let err = (res - ans).norm_l1() / ans.norm_l1();
+        assert!(err < 1.0e-10, "{mat:?}\n{res:?}\n{ans:?}");
+    });
+}
+
+#[test]
+fn exp_non_finite() {
+    [f64::NAN, f64::INFINITY, f64::NEG_INFINITY]
+        .iter()
+        .for_each(|&x| {
+            let mut mat = Matrix3::<f64>::identity() * 0.5;
+            mat[2][0] = x;
+            let res = mat.exp();
+            let all_nan = (0..3).all(|j| (0..3).all(|i| res[j][i].is_nan()));
+            assert!(all_nan, "{res:?}");
+        });
+}
+
+#[test]
+fn exp_stiff() {
+    // stiff system with the eigen values -1000 and -1
+    let p = Matrix2::new(1.0, 1.0, 1.0, 2.0);
+    let p_inv = p.invert().unwrap();
+    let mat = p * Matrix2::from_diagonal(Vector2::new(-1000.0, -1.0)) * p_inv;
+    let res = mat.exp();
+    let ans = p * Matrix2::from_diagonal(Vector2::new(f64::exp(-1000.0), f64::exp(-1.0))) * p_inv;
+    assert!((res - ans).norm_l1() < 1.0e-12, "{res:?}\n{ans:?}");
+
+    // non-normal matrix: exp(N) = I + N + N^2 / 2 + N^3 / 6 for nilpotent N
+    #[rustfmt::skip]
+    let nilp = Matrix4::new(
+        0.0, 0.0, 0.0, 0.0,
+        300.0, 0.0, 0.0, 0.0,
+        -200.0, 100.0, 0.0, 0.0,
+        400.0, 500.0, -600.0, 0.0,
+    );
+    let nilp2 = nilp * nilp;
+    let ans = Matrix4::identity() + nilp + nilp2 / 2.0 + nilp2 * nilp / 6.0;
+    let res = nilp.exp();
+    assert!(
+        (res - ans).norm_l1() < 1.0e-10 * ans.norm_l1(),
+        "{res:?}\n{ans:?}"
+    );
+
+    // inverse
+    (0..1000).for_each(|_i| {
+        let mat = random_matrix4() * 0.4;
+        let err = mat.exp() * (-mat).exp() - Matrix4::identity();
+        assert!(err.norm_l1() < 1.0e-6, "{mat:?}");
+    });
+}
+
+fn random_rotation_vector(max_angle: f64) -> Vector3<f64> {
+    let axis = Vector3::new(
+        2.0 * rand::random::<f64>() - 1.0,
+        2.0 * rand::random::<f64>() - 1.0,
+        2.0 * rand::random::<f64>() - 1.0,
+    )
+    .normalize();
+    axis * (max_angle * rand::random::<f64>())
+}
+
+#[test]
+fn so3() {
+    const EPS: f64 = 1.0e-12;
+    (0..1000).for_each(|_i| {
+        let omega = random_rotation_vector(std::f64::consts::PI);
+        let rot = lie::exp_so3(omega);
+        assert!((rot - lie::hat(omega).exp()).norm_l1() < EPS, "{omega:?}");
+        assert!((rot * rot.transpose() - Matrix3::identity()).norm_l1() < EPS);
+        assert!(
+            (lie::log_so3(rot) - omega).magnitude() < 1.0e-10,
+            "{omega:?}"
+        );
+    });
+
+    // small angles
+    let omega = Vector3::new(1.0e-9, -2.0e-9, 3.0e-10);
+    let rot = lie::exp_so3(omega);
+    assert!((rot - Matrix3::identity() - lie::hat(omega)).norm_l1() < 1.0e-17);
+    assert!((lie::log_so3(rot) - omega).magnitude() < 1.0e-20);
+    assert_eq!(lie::log_so3(Matrix3::<f64>::identity()), Vector3::zero());
+
+    // angles close to pi
+    let axis = Vector3::new(1.0, -2.0, 2.0) / 3.0;
+    [std::f64::consts::PI - 1.0e-9, std::f64::consts::PI]
+        .iter()
+        .for_each(|&theta| {
+            let rot = Matrix3::from_axis_angle(axis, Rad(theta));
+            let omega = lie::log_so3(rot);
+            assert!(f64::abs(omega.magnitude() - theta) < EPS, "{omega:?}");
+            assert!((lie::exp_so3(omega) - rot).norm_l1() < EPS, "{omega:?}");
+        });
+
+    // non-finite entries
+    [f64::NAN, f64::INFINITY, f64::NEG_INFINITY]
+        .into_iter()
+        .for_each(|x| {
+            let mut rot = Matrix3::from_axis_angle(axis, Rad(3.0));
+            rot[1][1] = x;
+            let omega = lie::log_so3(rot);
+            assert!(omega.x.is_nan() && omega.y.is_nan() && omega.z.is_nan());
+            let mut mat = Matrix4::from(rot);
+            mat[3] = Vector4::new(1.0, 2.0, 3.0, 1.0);
+            let (omega, v) = lie::log_se3(mat);
+            assert!(omega.x.is_nan() && v.x.is_nan() && v.y.is_nan() && v.z.is_nan());
+        });
+}
+
+#[test]
+fn se3() {
+    const EPS: f64 = 1.0e-10;
+    (0..1000).for_each(|_i| {
+        let omega = random_rotation_vector(std::f64::consts::PI);
+        let v = random_rotation_vector(10.0);
+        let mat = lie::exp_se3(omega, v);
+        let k = lie::hat(omega);
+        let twist = Matrix4::from_cols(
+            k.x.extend(0.0),
+            k.y.extend(0.0),
+            k.z.extend(0.0),
+            v.extend(0.0),
+        );
+        assert!((mat - twist.exp()).norm_l1() < EPS, "{omega:?} {v:?}");
+        let (omega0, v0) = lie::log_se3(mat);
+        assert!((omega0 - omega).magnitude() < EPS, "{omega:?} {omega0:?}");
+        assert!((v0 - v).magnitude() < EPS, "{v:?} {v0:?}");
+    });
+
+    // small angles
+    let (omega, v) = (
+        Vector3::new(1.0e-9, 0.0, -1.0e-9),
+        Vector3::new(1.0, 2.0, 3.0),
+    );
+    let mat = lie::exp_se3(omega, v);
+    let (omega0, v0) = lie::log_se3(mat);
+    assert!((omega0 - omega).magnitude() < 1.0e-20);
+    assert!((v0 - v).magnitude() < 1.0e-14);
+
+    // pure translation
+    let mat = Matrix4::from_translation(Vector3::new(1.0, -1.0, 2.0));
+    assert_eq!(
+        lie::log_se3(mat),
+        (Vector3::zero(), Vector3::new(1.0, -1.0, 2.0))
+    );
+}
@@ -1,6 +1,9 @@
 use cgmath::*;
 use matext4cgmath::*;
 
+mod common;
+use common::*;
+
 #[test]
 fn matrix2() {
     const EPS: f64 = 1.0e-10;
@@ -23,3 +26,205 @@ fn matrix3() {
     assert!(f64::abs(mat.norm_l2() - f64::sqrt(6.0)) < EPS);
     assert!(f64::abs(mat.norm_linf() - 3.0) < EPS);
 }
+
+#[test]
+fn condition_number() {
+    const EPS: f64 = 1.0e-8;
+    (0..10000).for_each(|_i| {
+        let mat = random_matrix4();
+        let inv = mat.invert().unwrap();
+        let (cond_l1, cond_l2, cond_linf) = (mat.cond_l1(), mat.cond_l2(), mat.cond_linf());
+        assert!(f64::abs(cond_l1 / (mat.norm_l1() * inv.norm_l1()) - 1.0) < EPS);
+        let (_, sigma, _) = mat.svd();
+        assert!(f64::abs(cond_l2 / (sigma[0][0] / sigma[3][3]) - 1.0) < EPS);
+        assert!(f64::abs(cond_l2 / (sigma[0][0] * inv.svd().1[0][0]) - 1.0) < EPS);
+        assert!(f64::abs(cond_linf / (mat.norm_linf() * inv.norm_linf()) - 1.0) < EPS);
+        assert!(f64::abs(mat.transpose().cond_l1() / cond_linf - 1.0) < EPS);
+        assert!(cond_l1 >= 1.0 && cond_l2 >= 1.0 && cond_linf >= 1.0);
+        // equivalence of the norms in dimension 4
+        assert!(cond_l2 <= 4.0 * cond_l1 * (1.0 + EPS) && cond_l1 <= 4.0 * cond_l2 * (1.0 + EPS));
+        // invariant under orthogonal transforms and scaling
+        let q = random_matrix4().qr().0;
+        assert!(f64::abs((q * mat * 3.0).cond_l2() / cond_l2 - 1.0) < EPS);
+    });
+
+    let mat = Matrix3::from_diagonal(Vector3::new(1.0, -2.0, 4.0));
+    assert_eq!(mat.cond_l1(), 4.0);
+    assert_eq!(mat.cond_l2(), 4.0);
+    assert_eq!(mat.cond_linf(), 4.0);
+    assert_eq!(Matrix4::<f64>::identity().cond_l2(), 1.0);
+
+    // singular
+    let mut mat = random_matrix4();
+    mat[2] = mat[0] * 2.0 - mat[1];
+    assert_eq!(mat.cond_l1(), f64::INFINITY);
+    assert_eq!(mat.cond_linf(), f64::INFINITY);
+    assert!(mat.cond_l2().is_infinite());
+    let mat = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    assert_eq!(mat.cond_l1(), f64::INFINITY);
+    assert_eq!(mat.cond_l2(), f64::INFINITY);
+    let zero = Matrix3::<f64>::zero();
+    assert_eq!(zero.cond_l1(), f64::INFINITY);
+    assert_eq!(zero.cond_l2(), f64::INFINITY);
+    assert_eq!(zero.cond_linf(), f64::INFINITY);
+
+    // non-finite
+    for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let mut mat = random_matrix4();
+        mat[1][3] = x;
+        assert!(mat.cond_l1().is_nan());
+        assert!(mat.cond_l2().is_nan());
+        assert!(mat.cond_linf().is_nan());
+        let mut mat = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+        mat[0][0] = x;
+        assert!(mat.cond_l1().is_nan() && mat.cond_l2().is_nan() && mat.cond_linf().is_nan());
+    }
+}
+
+#[test]
+fn entrywise_and_schatten() {
+    const EPS: f64 = 1.0e-10;
+    (0..10000).for_each(|_i| {
+        let mat = random_matrix4();
+        let entries: [[f64; 4]; 4] = mat.into();
+        let sum = entries.iter().flatten().fold(0.0, |sum, x| sum + x * x);
+        let max = entries
+            .iter()
+            .flatten()
+            .fold(0.0, |max, x| f64::max(max, f64::abs(*x)));
+        let (frobenius, norm_max) = (mat.norm_frobenius(), mat.norm_max());
+        assert!(f64::abs(frobenius - f64::sqrt(sum)) < EPS);
+        assert_eq!(norm_max, max);
+
+        let (_, sigma, _) = mat.svd();
+        let sigma = [sigma[0][0], sigma[1][1], sigma[2][2], sigma[3][3]];
+        let nuclear = sigma.iter().sum::<f64>();
+        assert!(f64::abs(mat.norm_nuclear() - nuclear) < EPS);
+        assert!(f64::abs(mat.norm_schatten(2.0) - frobenius) < EPS);
+        assert!(f64::abs(mat.norm_schatten(f64::INFINITY) - sigma[0]) < EPS);
+        // relations to the operator norm
+        assert!(sigma[0] <= frobenius + EPS && frobenius <= 2.0 * sigma[0] + EPS);
+        assert!(norm_max <= sigma[0] + EPS && sigma[0] <= 4.0 * norm_max + EPS);
+        // the Schatten norms are non-increasing in p
+        let norms = [1.0, 1.5, 2.0, 3.0, 10.0, f64::INFINITY].map(|p| mat.norm_schatten(p));
+        norms
+            .windows(2)
+            .for_each(|w| assert!(w[0] >= w[1] - EPS, "{norms:?}"));
+        // unitary invariance
+        let q = random_matrix4().qr().0;
+        assert!(f64::abs((q * mat).norm_frobenius() - frobenius) < EPS);
+        assert!(f64::abs((q * mat).norm_nuclear() - nuclear) < EPS);
+    });
+
+    // no overflow
+    let mat = Matrix2::new(1.0e200, 1.0e200, -1.0e200, 1.0e200);
+    assert!(f64::abs(mat.norm_frobenius() / 2.0e200 - 1.0) < EPS);
+    assert!(f64::abs(mat.norm_schatten(4.0) / (f64::powf(2.0, 0.75) * 1.0e200) - 1.0) < EPS);
+    assert_eq!(Matrix3::<f64>::zero().norm_frobenius(), 0.0);
+    assert_eq!(Matrix3::<f64>::zero().norm_schatten(3.0), 0.0);
+}
+
+#[test]
+fn norm_lp() {
+    const EPS: f64 = 1.0e-8;
+    let lp = |x: Vector4<f64>, p: f64| -> f64 {
+        (x[0].abs().powf(p) + x[1].abs().powf(p) + x[2].abs().powf(p) + x[3].abs().powf(p))
+            .powf(1.0 / p)
+    };
+    (0..100).for_each(|_i| {
+        let mat = random_matrix4();
+        let (norm_l1, norm_linf) = (mat.norm_l1(), mat.norm_linf());
+        let norm_l2 = mat.svd().1[0][0];
+        assert_eq!(mat.norm_lp(1.0), norm_l1);
+        assert_eq!(mat.norm_lp(f64::INFINITY), norm_linf);
+        assert!(f64::abs(mat.norm_lp(2.0) / norm_l2 - 1.0) < EPS);
+        // continuity at the exponents of the exact norms
+        assert!(f64::abs(mat.norm_lp(1.0 + 1.0e-9) / norm_l1 - 1.0) < 1.0e-6);
+        assert!(f64::abs(mat.norm_lp(2.0 + 1.0e-9) / norm_l2 - 1.0) < 1.0e-6);
+        assert!(f64::abs(mat.norm_lp(1.0e9) / norm_linf - 1.0) < 1.0e-6);
+
+        [1.2, 1.5, 3.0, 4.0, 10.0].into_iter().for_each(|p| {
+            let norm = mat.norm_lp(p);
+            // duality
+            let q = p / (p - 1.0);
+            assert!(
+                f64::abs(mat.transpose().norm_lp(q) / norm - 1.0) < EPS,
+                "{p} {} {norm}",
+                mat.transpose().norm_lp(q)
+            );
+            // Riesz-Thorin interpolation
+            let bound = match p < 2.0 {
+                true => norm_l1.powf(2.0 / p - 1.0) * norm_l2.powf(2.0 - 2.0 / p),
+                false => norm_l2.powf(2.0 / p) * norm_linf.powf(1.0 - 2.0 / p),
+            };
+            assert!(norm <= bound * (1.0 + EPS), "{norm} {bound}");
+            // lower bounds by arbitrary vectors
+            (0..10).for_each(|_j| {
+                let x = Vector4::new(
+                    2.0 * rand::random::<f64>() - 1.0,
+                    2.0 * rand::random::<f64>() - 1.0,
+                    2.0 * rand::random::<f64>() - 1.0,
+                    2.0 * rand::random::<f64>() - 1.0,
+                );
+                assert!(lp(mat * x, p) <= norm * lp(x, p) * (1.0 + EPS));
+            });
+        });
+    });
+
+    let mat = Matrix3::new(1.0, 1.0, 1.0, -1.0, 0.0, 1.0, 1.0, -2.0, 1.0);
+    assert!(f64::abs(mat.norm_lp(2.0) - mat.norm_l2()) < EPS);
+    assert!(mat.norm_lp(0.5).is_nan());
+    assert!(mat.norm_lp(f64::NAN).is_nan());
+    assert_eq!(Matrix4::<f64>::zero().norm_lp(3.0), 0.0);
+}
+
+#[test]
+fn logarithmic_norm() {
+    const EPS: f64 = 1.0e-8;
+    (0..10000).for_each(|_i| {
+        let mat = random_matrix4();
+        let (mu_l1, mu_l2, mu_linf) = (mat.log_norm_l1(), mat.log_norm_l2(), mat.log_norm_linf());
+        // definition by the one-sided derivative
+        let h = 1.0e-3;
+        let id = Matrix4::identity();
+        assert!(f64::abs(((id + mat * h).norm_l1() - 1.0) / h - mu_l1) < EPS);
+        assert!(f64::abs(((id + mat * h).norm_linf() - 1.0) / h - mu_linf) < EPS);
+        let h = 1.0e-7;
+        let sigma = (id + mat * h).svd().1[0][0];
+        assert!(f64::abs((sigma - 1.0) / h - mu_l2) < 1.0e-4);
+        assert!(mu_l1 <= mat.norm_l1() && mu_linf <= mat.norm_linf());
+        assert_eq!(mu_linf, mat.transpose().log_norm_l1());
+        // bounds of the spectral abscissa
+        let abscissa = mat
+            .eigenvalues_by_schur()
+            .iter()
+            .fold(f64::NEG_INFINITY, |max, e| f64::max(max, e.re));
+        assert!(abscissa <= mu_l1 + EPS && abscissa <= mu_l2 + EPS && abscissa <= mu_linf + EPS);
+        // bounds of the exponential
+        let t = 2.0 * rand::random::<f64>();
+        let exp = (mat * t).exp();
+        assert!(exp.norm_l1() <= f64::exp(t * mu_l1) * (1.0 + EPS));
+        assert!(exp.svd().1[0][0] <= f64::exp(t * mu_l2) * (1.0 + EPS));
+        assert!(exp.norm_linf() <= f64::exp(t * mu_linf) * (1.0 + EPS));
+    });
+
+    // the symmetric part determines the L^2 logarithmic norm
+    let mat = Matrix3::new(-1.0, 5.0, 0.0, -5.0, -2.0, 0.0, 0.0, 0.0, -3.0);
+    assert!(f64::abs(mat.log_norm_l2() + 1.0) < EPS);
+    assert_eq!(mat.log_norm_l1(), 4.0);
+    let (t, exp) = (0.7, (mat * 0.7).exp());
+    assert!(exp.norm_l2() <= f64::exp(-t) * (1.0 + EPS));
+
+    // non-finite
+    for (i, j) in [(0, 0), (1, 3), (3, 2)] {
+        let mut mat = random_matrix4();
+        mat[i][j] = f64::NAN;
+        assert!(mat.log_norm_l1().is_nan());
+        assert!(mat.log_norm_l2().is_nan());
+        assert!(mat.log_norm_linf().is_nan());
+    }
+    let mut mat = random_matrix4();
+    mat[1][3] = f64::NEG_INFINITY;
+    assert_eq!(mat.log_norm_l1(), f64::INFINITY);
+    assert_eq!(mat.log_norm_linf(), f64::INFINITY);
+}
@@ -0,0 +1,95 @@
+use cgmath::*;
+use matext4cgmath::*;
+use std::f64::consts::PI;
+
+mod common;
+use common::*;
+
+fn random_symmetric3() -> Matrix3<f64> {
+    let mat = Matrix3::from_cols(random_vector3(), random_vector3(), random_vector3());
+    (mat + mat.transpose()) / 2.0
+}
+
+#[test]
+fn invariants() {
+    const EPS: f64 = 1.0e-10;
+    (0..10000).for_each(|_i| {
+        let mat = Matrix3::from_cols(random_vector3(), random_vector3(), random_vector3());
+        let [i1, i2, i3] = mat.principal_invariants();
+        assert_eq!(i1, mat.trace());
+        assert!(f64::abs(i3 - mat.determinant()) < EPS);
+        let sq = mat * mat;
+        assert!(f64::abs(i2 - (i1 * i1 - sq.trace()) / 2.0) < EPS);
+
+        let (dev, vol) = (mat.deviatoric(), mat.volumetric());
+        assert!((dev + vol - mat).norm_l1() < EPS);
+        assert!(f64::abs(dev.trace()) < EPS);
+        assert_eq!(vol, Matrix3::identity() * (i1 / 3.0));
+        assert!(vol.deviatoric().norm_l1() < EPS);
+    });
+}
+
+#[test]
+fn principal_values() {
+    const EPS: f64 = 1.0e-8;
+    (0..10000).for_each(|_i| {
+        let mat = random_symmetric3();
+        let values = mat.principal_values();
+        let (eigens, _) = mat.symmetric_eigen();
+        assert!(values.x >= values.y && values.y >= values.z);
+        assert!((values - Vector3::new(eigens.z, eigens.y, eigens.x)).magnitude() < EPS);
+
+        // von Mises stress by the principal stresses
+        let (s1, s2, s3) = (values.x, values.y, values.z);
+        let von_mises =
+            f64::sqrt(((s1 - s2).powi(2) + (s2 - s3).powi(2) + (s3 - s1).powi(2)) / 2.0);
+        assert!(f64::abs(mat.von_mises() - von_mises) < EPS);
+
+        // Lode angle
+        let theta = mat.lode_angle();
+        assert!((0.0..=PI / 3.0).contains(&theta));
+        let dev = mat.deviatoric();
+        let j2 = (dev * dev).trace() / 2.0;
+        let cos = 3.0 * f64::sqrt(3.0) / 2.0 * dev.determinant() / j2.powf(1.5);
+        assert!(f64::abs(f64::cos(3.0 * theta) - cos) < EPS);
+
+        // invariant under rotations, and the shift by the hydrostatic pressure
+        let rot = Matrix3::from_axis_angle(
+            random_vector3().normalize(),
+            Rad(2.0 * PI * rand::random::<f64>()),
+        );
+        let rotated = rot * mat * rot.transpose() + Matrix3::identity() * 2.0;
+        assert!((rotated.principal_values() - values - Vector3::from_value(2.0)).magnitude() < EPS);
+        assert!(f64::abs(rotated.von_mises() - mat.von_mises()) < EPS);
+        assert!(f64::abs(f64::cos(3.0 * rotated.lode_angle()) - cos) < EPS);
+
+        // only the symmetric part is referred
+        let skew = Matrix3::from_cols(random_vector3(), random_vector3(), random_vector3());
+        let asym = mat + (skew - skew.transpose());
+        assert!((asym.principal_values() - values).magnitude() < EPS);
+        assert!(f64::abs(asym.von_mises() - mat.von_mises()) < EPS);
+    });
+
+    // uniaxial compression
+    let mat = Matrix3::from_diagonal(Vector3::new(0.0, -2.0, 0.0));
+    assert!(f64::abs(mat.lode_angle() - PI / 3.0) < EPS);
+    assert!((mat.principal_values() - Vector3::new(0.0, 0.0, -2.0)).magnitude() < EPS);
+    // multiple principal values
+    let mat = Matrix3::from_diagonal(Vector3::new(1.0, 4.0, 1.0));
+    assert!((mat.principal_values() - Vector3::new(4.0, 1.0, 1.0)).magnitude() < EPS);
+    // purely volumetric
+    let mat = Matrix3::from_value(-5.0_f64);
+    assert_eq!(mat.von_mises(), 0.0);
+    assert!(mat.lode_angle().is_nan());
+    assert_eq!(mat.principal_values(), Vector3::from_value(-5.0));
+    // non-finite
+    for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        for (i, j) in [(0, 0), (0, 1), (2, 1)] {
+            let mut mat = Matrix3::from_diagonal(Vector3::new(1.0, 2.0, 3.0));
+            mat[i][j] = x;
+            assert!(mat.lode_angle().is_nan());
+            let values = mat.principal_values();
+            assert!(values.x.is_nan() && values.y.is_nan() && values.z.is_nan());
+        }
+    }
+}